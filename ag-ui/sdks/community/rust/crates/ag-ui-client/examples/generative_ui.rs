@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use ag_ui_client::agent::{AgentError, AgentStateMutation, RunAgentParams};
 use ag_ui_client::core::AgentState;
-use ag_ui_client::core::event::{StateDeltaEvent, StateSnapshotEvent};
+use ag_ui_client::core::event::StateSnapshotEvent;
 use ag_ui_client::core::types::Message;
 use ag_ui_client::subscriber::{AgentSubscriber, AgentSubscriberParams};
 use ag_ui_client::{Agent, HttpAgent};
@@ -50,6 +50,17 @@ pub struct Plan {
 
 impl AgentState for Plan {}
 
+fn log_plan(plan: &Plan) {
+    info!("   Plan with {} steps:", plan.steps.len());
+    for (i, step) in plan.steps.iter().enumerate() {
+        let status_icon = match step.status {
+            StepStatus::Pending => "[ ]",
+            StepStatus::Completed => "[X]",
+        };
+        info!("   {}. {} {}", i + 1, status_icon, step.description);
+    }
+}
+
 pub struct GenerativeUiSubscriber;
 
 impl GenerativeUiSubscriber {
@@ -66,53 +77,14 @@ impl AgentSubscriber<Plan, ()> for GenerativeUiSubscriber {
         _params: AgentSubscriberParams<'async_trait, Plan, ()>,
     ) -> Result<AgentStateMutation<Plan>, AgentError> {
         info!("State snapshot received:");
-        let plan = &event.snapshot;
-        info!("   Plan with {} steps:", plan.steps.len());
-        for (i, step) in plan.steps.iter().enumerate() {
-            let status_icon = match step.status {
-                StepStatus::Pending => "[ ]",
-                StepStatus::Completed => "[X]",
-            };
-            info!("   {}. {} {}", i + 1, status_icon, step.description);
-        }
+        log_plan(&event.snapshot);
         Ok(AgentStateMutation::default())
     }
 
-    async fn on_state_delta_event(
-        &self,
-        event: &StateDeltaEvent,
-        _params: AgentSubscriberParams<'async_trait, Plan, ()>,
-    ) -> Result<AgentStateMutation<Plan>, AgentError> {
-        info!("State delta received:");
-        for patch in &event.delta {
-            match patch.get("op").and_then(|v| v.as_str()) {
-                Some("replace") => {
-                    if let (Some(path), Some(value)) = (
-                        patch.get("path").and_then(|v| v.as_str()),
-                        patch.get("value"),
-                    ) {
-                        if path.contains("/status") {
-                            let status = value.as_str().unwrap_or("unknown");
-                            let status_icon = match status {
-                                "completed" => "[X]",
-                                "pending" => "[ ]",
-                                _ => "[?]",
-                            };
-                            info!("   {} Step status updated to: {}", status_icon, status);
-                        } else if path.contains("/description") {
-                            info!(
-                                "   Step description updated to: {}",
-                                value.as_str().unwrap_or("unknown")
-                            );
-                        }
-                    }
-                }
-                Some(op) => info!("   Operation: {}", op),
-                None => info!("   Unknown operation"),
-            }
-        }
-        Ok(AgentStateMutation::default())
-    }
+    // `ag-ui-client`'s run loop already applies `StateDeltaEvent`'s RFC 6902 patch and
+    // re-deserializes it into `Plan` before this callback and `on_state_changed` run, so there's
+    // no patch-parsing to do here -- `params.state` in `on_state_changed` below is already the
+    // fully-typed, up-to-date plan.
 
     async fn on_state_changed(
         &self,
@@ -123,10 +123,12 @@ impl AgentSubscriber<RecipeSnapshot, ()> for RecipeSubscriber {
 
     async fn on_state_delta_event(
         &self,
-        event: &StateDeltaEvent,
-        _params: AgentSubscriberParams<'async_trait, RecipeSnapshot, ()>,
+        _event: &StateDeltaEvent,
+        params: AgentSubscriberParams<'async_trait, RecipeSnapshot, ()>,
     ) -> Result<AgentStateMutation<RecipeSnapshot>, AgentError> {
-        info!("Received state delta event {:#?}", event.delta);
+        // The JSON Patch in `event.delta` is already applied to `RecipeSnapshot` by the time this
+        // runs, so `params.state` is the patched, typed recipe -- no manual patch-parsing needed.
+        info!("Recipe patched to: {:#?}", params.state);
         Ok(AgentStateMutation::default())
     }
 
@@ -1,14 +1,18 @@
+use futures::Stream;
 use futures::stream::StreamExt;
 use std::collections::HashSet;
+use std::sync::Arc;
 
+use crate::channel_subscriber::{FanOutItem, FanOutSubscriber};
 use crate::core::JsonValue;
+use crate::core::event::Event;
 use crate::core::types::{
-    AgentId, Context, Message, MessageId, RunAgentInput, RunId, ThreadId, Tool,
+    AgentId, Context, Message, MessageContent, MessageId, RunAgentInput, RunId, ThreadId, Tool,
 };
 use crate::core::{AgentState, FwdProps};
 use crate::event_handler::EventHandler;
 use crate::stream::EventStream;
-use crate::subscriber::IntoSubscribers;
+use crate::subscriber::{AgentSubscriber, IntoSubscribers, Subscribers};
 
 /// Configuration for an Agent.
 #[derive(Debug, Clone)]
@@ -92,7 +96,7 @@ where
         self.messages.push(msg);
         self
     }
-    pub fn user(mut self, content: impl Into<String>) -> Self {
+    pub fn user(mut self, content: impl Into<MessageContent>) -> Self {
         self.messages.push(Message::User {
             id: MessageId::random(),
             content: content.into(),
@@ -120,7 +124,7 @@ pub struct RunAgentResult<StateT: AgentState> {
 
 pub type AgentRunState<StateT, FwdPropsT> = RunAgentInput<StateT, FwdPropsT>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct AgentStateMutation<StateT = JsonValue> {
     pub messages: Option<Vec<Message>>,
     pub state: Option<StateT>,
@@ -137,6 +141,53 @@ impl<StateT> Default for AgentStateMutation<StateT> {
     }
 }
 
+impl<StateT: AgentState> AgentStateMutation<StateT> {
+    /// Applies a `StateDeltaEvent`'s RFC 6902 JSON Patch `delta` to `current_state` and wraps the
+    /// result in a mutation, so a subscriber overriding
+    /// [`AgentSubscriber::on_state_delta_event`](crate::subscriber::AgentSubscriber::on_state_delta_event)
+    /// to react to typed state doesn't have to hand-apply the patch itself -- it can just return
+    /// this.
+    ///
+    /// This is the same application the run loop already performs by default for every
+    /// `StateDeltaEvent` before any subscriber runs; this helper exists for subscribers that want
+    /// the patched, typed state as part of their own mutation (e.g. to also update `messages` in
+    /// the same `AgentStateMutation`).
+    pub fn from_json_patch(
+        delta: &[JsonValue],
+        current_state: &StateT,
+    ) -> Result<Self, AgentError> {
+        let state = crate::core::apply_state_delta(current_state, delta).map_err(|err| {
+            AgentError::Execution {
+                message: format!("Failed to apply state patch: {err}"),
+            }
+        })?;
+
+        Ok(Self {
+            messages: None,
+            state: Some(state),
+            stop_propagation: false,
+        })
+    }
+}
+
+/// Describes one failed mid-run transport attempt that a resilient [`Agent`] is about to retry,
+/// e.g. a [`crate::HttpAgent`] configured via `with_resumable_stream` reconnecting a dropped SSE
+/// stream.
+///
+/// Surfaced to subscribers via
+/// [`AgentSubscriber::on_transport_retry`](crate::subscriber::AgentSubscriber::on_transport_retry)
+/// before each retry attempt; `on_run_failed` only fires afterwards, once every retry is
+/// exhausted.
+#[derive(Debug, Clone)]
+pub struct TransportRetryEvent {
+    /// How many retries have been attempted for this disconnect so far (starts at 1).
+    pub attempt: u32,
+    /// How long the agent will wait before making this retry.
+    pub delay: std::time::Duration,
+    /// A human-readable description of the error that triggered the retry.
+    pub message: String,
+}
+
 // Error types
 pub use crate::error::AgUiClientError as AgentError;
 
@@ -246,4 +297,85 @@ where
     fn agent_id(&self) -> Option<&AgentId> {
         None
     }
+
+    /// Like [`Agent::run_agent`], but decouples event consumption from the subscriber trait: the
+    /// run drives forward on a spawned task while the caller gets back a plain
+    /// `futures::Stream<Item = Event<StateT>>` to compose with other stream combinators (a UI
+    /// renderer, a logger, a metrics sink), alongside a [`tokio::task::JoinHandle`] resolving to
+    /// the same [`RunAgentResult`] `run_agent` would have returned.
+    ///
+    /// Internally this is just `run_agent` with an extra [`crate::channel_subscriber::FanOutSubscriber`]
+    /// appended to `subscribers`, so `subscribers`'s own callbacks still fire as usual; dropping
+    /// the returned stream doesn't cancel the run, since the task keeps driving it to completion
+    /// regardless. Requires `Self: Clone + 'static` to move an owned copy onto the spawned task.
+    async fn run_agent_stream(
+        &self,
+        params: &RunAgentParams<StateT, FwdPropsT>,
+        subscribers: impl IntoSubscribers<StateT, FwdPropsT>,
+    ) -> (
+        impl Stream<Item = Event<StateT>> + Unpin,
+        tokio::task::JoinHandle<Result<RunAgentResult<StateT>, AgentError>>,
+    )
+    where
+        Self: Clone + 'static,
+    {
+        const BROADCAST_BUFFER: usize = 256;
+
+        let (broadcaster, stream) = FanOutSubscriber::new(BROADCAST_BUFFER);
+        // This method's own contract is a plain `Stream<Item = Event<StateT>>`; a
+        // `FanOutItem::Lagged` here is already logged by `FanOutSubscriber` itself, so it's
+        // dropped rather than threaded through another layer of item type.
+        let stream = stream.filter_map(|item| {
+            futures::future::ready(match item {
+                FanOutItem::Event(event) => Some(event),
+                FanOutItem::Lagged { .. } => None,
+            })
+        });
+        let mut all_subscribers: Vec<Arc<dyn AgentSubscriber<StateT, FwdPropsT>>> =
+            (&subscribers.into_subscribers())
+                .into_iter()
+                .cloned()
+                .collect();
+        all_subscribers.push(Arc::new(broadcaster));
+        let subscribers = Subscribers::new(all_subscribers);
+
+        let agent = self.clone();
+        let params = params.clone();
+        let handle = tokio::spawn(async move { agent.run_agent(&params, subscribers).await });
+
+        (stream, handle)
+    }
+
+    /// Like [`Agent::run_agent`], but retries a failed run according to `policy`, sleeping out
+    /// `backoff` (or the error's own [`crate::retry::RetryPolicy::backoff_hint`]) between attempts, and
+    /// returning the last error once `policy` gives up.
+    ///
+    /// This retries the *entire* run -- connect, SSE consumption, and event handling -- so it
+    /// complements rather than replaces [`crate::HttpAgent`]'s own finer-grained retry of just
+    /// its initial POST; see [`crate::retry::RetryPolicy`] for how the two relate.
+    async fn run_agent_with_retry(
+        &self,
+        params: &RunAgentParams<StateT, FwdPropsT>,
+        subscribers: impl IntoSubscribers<StateT, FwdPropsT>,
+        policy: &(dyn crate::retry::RetryPolicy),
+        backoff: &crate::retry::ExponentialBackoff,
+    ) -> Result<RunAgentResult<StateT>, AgentError> {
+        let subscribers = subscribers.into_subscribers();
+        let mut attempt = 0;
+        loop {
+            match self.run_agent(params, subscribers.clone()).await {
+                Ok(result) => return Ok(result),
+                Err(err) => {
+                    if !policy.should_retry(&err, attempt) {
+                        return Err(err);
+                    }
+                    let delay = policy
+                        .backoff_hint(&err)
+                        .unwrap_or_else(|| backoff.delay_for(attempt));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use reqwest::Response;
+use std::pin::Pin;
+
+use crate::core::JsonValue;
+use crate::core::event::{
+    MessagesSnapshotEvent, RunErrorEvent, RunFinishedEvent, RunStartedEvent, StateDeltaEvent,
+    StateSnapshotEvent, TextMessageContentEvent, TextMessageEndEvent, TextMessageStartEvent,
+    ToolCallArgsEvent, ToolCallEndEvent, ToolCallStartEvent,
+};
+use crate::error::AgUiClientError;
+use crate::sse::{SseEvent, SseResponseExt};
+
+/// A typed, decoded view of the AG-UI events carried over an SSE stream.
+///
+/// Unlike the raw [`SseEvent`] produced by [`SseResponseExt::event_source`], every variant
+/// here already has its `data` payload deserialized into the matching event struct, so callers
+/// no longer need to match on the `event:` name and call `serde_json::from_str` themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgUiEvent {
+    RunStarted(RunStartedEvent),
+    TextMessageStart(TextMessageStartEvent),
+    TextMessageContent(TextMessageContentEvent),
+    TextMessageEnd(TextMessageEndEvent),
+    ToolCallStart(ToolCallStartEvent),
+    ToolCallArgs(ToolCallArgsEvent),
+    ToolCallEnd(ToolCallEndEvent),
+    StateSnapshot(StateSnapshotEvent),
+    StateDelta(StateDeltaEvent),
+    MessagesSnapshot(MessagesSnapshotEvent),
+    RunFinished(RunFinishedEvent),
+    RunError(RunErrorEvent),
+    /// An event whose name didn't match any known AG-UI event type.
+    Custom { name: String, data: JsonValue },
+}
+
+impl AgUiEvent {
+    /// Decode a raw [`SseEvent`] into a typed [`AgUiEvent`].
+    ///
+    /// The event name is taken from the SSE `event:` field when present, falling back to the
+    /// `type` field inside the JSON `data` payload (the shape the AG-UI protocol uses when the
+    /// server doesn't set `event:` at all, relying purely on the tagged JSON body).
+    pub fn from_sse(sse_event: &SseEvent) -> Result<Self, AgUiClientError> {
+        let name = match &sse_event.event {
+            Some(name) => name.clone(),
+            None => {
+                let probe: JsonValue =
+                    serde_json::from_str(&sse_event.data).map_err(|e| AgUiClientError::SseParse {
+                        message: format!(
+                            "event has no 'event:' field and data isn't valid JSON: {e}"
+                        ),
+                    })?;
+                probe
+                    .get("type")
+                    .and_then(JsonValue::as_str)
+                    .map(str::to_string)
+                    .ok_or_else(|| AgUiClientError::SseParse {
+                        message: "event has no 'event:' field and no 'type' in data".to_string(),
+                    })?
+            }
+        };
+
+        macro_rules! decode {
+            ($variant:ident) => {
+                serde_json::from_str(&sse_event.data)
+                    .map(AgUiEvent::$variant)
+                    .map_err(|e| AgUiClientError::SseParse {
+                        message: format!(
+                            "failed to decode {name} event (data: {}): {e}",
+                            sse_event.data
+                        ),
+                    })
+            };
+        }
+
+        match name.as_str() {
+            "RUN_STARTED" => decode!(RunStarted),
+            "TEXT_MESSAGE_START" => decode!(TextMessageStart),
+            "TEXT_MESSAGE_CONTENT" => decode!(TextMessageContent),
+            "TEXT_MESSAGE_END" => decode!(TextMessageEnd),
+            "TOOL_CALL_START" => decode!(ToolCallStart),
+            "TOOL_CALL_ARGS" => decode!(ToolCallArgs),
+            "TOOL_CALL_END" => decode!(ToolCallEnd),
+            "STATE_SNAPSHOT" => decode!(StateSnapshot),
+            "STATE_DELTA" => decode!(StateDelta),
+            "MESSAGES_SNAPSHOT" => decode!(MessagesSnapshot),
+            "RUN_FINISHED" => decode!(RunFinished),
+            "RUN_ERROR" => decode!(RunError),
+            _ => {
+                let data = serde_json::from_str(&sse_event.data).unwrap_or(JsonValue::Null);
+                Ok(AgUiEvent::Custom { name, data })
+            }
+        }
+    }
+}
+
+/// Extension trait adapting a [`reqwest::Response`] directly into a typed [`AgUiEvent`] stream.
+#[async_trait]
+pub trait TypedEventResponseExt {
+    /// Converts a response into a stream of decoded [`AgUiEvent`]s.
+    async fn event_stream(
+        self,
+    ) -> Pin<Box<dyn Stream<Item = Result<AgUiEvent, AgUiClientError>> + Send>>;
+}
+
+#[async_trait]
+impl TypedEventResponseExt for Response {
+    async fn event_stream(
+        self,
+    ) -> Pin<Box<dyn Stream<Item = Result<AgUiEvent, AgUiClientError>> + Send>> {
+        let stream = self.event_source().await;
+        Box::pin(stream.map(|result| result.and_then(|sse_event| AgUiEvent::from_sse(&sse_event))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_event_via_event_field() {
+        let sse_event = SseEvent {
+            event: Some("RUN_STARTED".to_string()),
+            id: None,
+            data: r#"{"threadId":"00000000-0000-0000-0000-000000000000","runId":"00000000-0000-0000-0000-000000000000"}"#.to_string(),
+            retry: None,
+        };
+        let event = AgUiEvent::from_sse(&sse_event).unwrap();
+        assert!(matches!(event, AgUiEvent::RunStarted(_)));
+    }
+
+    #[test]
+    fn decodes_known_event_via_type_field() {
+        let sse_event = SseEvent {
+            event: None,
+            id: None,
+            data: r#"{"type":"RUN_ERROR","message":"boom"}"#.to_string(),
+            retry: None,
+        };
+        let event = AgUiEvent::from_sse(&sse_event).unwrap();
+        match event {
+            AgUiEvent::RunError(e) => assert_eq!(e.message, "boom"),
+            other => panic!("unexpected variant: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_event_name_becomes_custom() {
+        let sse_event = SseEvent {
+            event: Some("SOMETHING_NEW".to_string()),
+            id: None,
+            data: r#"{"foo":"bar"}"#.to_string(),
+            retry: None,
+        };
+        let event = AgUiEvent::from_sse(&sse_event).unwrap();
+        match event {
+            AgUiEvent::Custom { name, data } => {
+                assert_eq!(name, "SOMETHING_NEW");
+                assert_eq!(data["foo"], "bar");
+            }
+            other => panic!("unexpected variant: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_failure_reports_event_and_data() {
+        let sse_event = SseEvent {
+            event: Some("RUN_STARTED".to_string()),
+            id: None,
+            data: "not json".to_string(),
+            retry: None,
+        };
+        let err = AgUiEvent::from_sse(&sse_event).unwrap_err();
+        match err {
+            AgUiClientError::SseParse { message } => {
+                assert!(message.contains("RUN_STARTED"));
+                assert!(message.contains("not json"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Stream-based alternatives to [`AgentSubscriber`] for consumers that only want to observe a
+//! run instead of implementing its ~25 callback methods.
+//!
+//! [`ChannelSubscriber`] forwards every dispatched event into a `tokio::sync::mpsc` channel and
+//! hands back a `Stream`, so a caller can `while let Some(event) = stream.next().await` or
+//! `tokio::select!` across multiple runs in idiomatic async code. [`FanOutSubscriber`] is the
+//! same idea built on `tokio::sync::broadcast`, so several independent consumers (a logger, a
+//! UI, metrics) can each get their own receiver from a single run.
+
+use futures::{Stream, StreamExt};
+use log::warn;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream, UnboundedReceiverStream};
+
+use crate::agent::{AgentError, AgentStateMutation};
+use crate::core::event::Event;
+use crate::core::{AgentState, FwdProps, JsonValue};
+use crate::subscriber::{AgentSubscriber, AgentSubscriberParams};
+
+enum Sender<StateT: AgentState> {
+    Unbounded(mpsc::UnboundedSender<Event<StateT>>),
+    Bounded(mpsc::Sender<Event<StateT>>),
+}
+
+/// An [`AgentSubscriber`] that forwards every dispatched event into a `tokio::sync::mpsc`
+/// channel instead of requiring a bespoke trait impl.
+///
+/// Construct with [`ChannelSubscriber::unbounded`] or [`ChannelSubscriber::bounded`], both of
+/// which hand back the paired `Stream<Item = Event<StateT>>` alongside the subscriber.
+pub struct ChannelSubscriber<StateT: AgentState = JsonValue> {
+    sender: Sender<StateT>,
+}
+
+impl<StateT: AgentState> ChannelSubscriber<StateT> {
+    /// An unbounded channel: forwarding an event never blocks the run, but the queue can grow
+    /// without bound if the consumer falls behind.
+    pub fn unbounded() -> (Self, impl Stream<Item = Event<StateT>> + Unpin) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (
+            Self {
+                sender: Sender::Unbounded(tx),
+            },
+            UnboundedReceiverStream::new(rx),
+        )
+    }
+
+    /// A bounded channel of `capacity`: once full, forwarding an event waits for the consumer to
+    /// make room, applying backpressure to the run itself.
+    pub fn bounded(capacity: usize) -> (Self, impl Stream<Item = Event<StateT>> + Unpin) {
+        let (tx, rx) = mpsc::channel(capacity);
+        (
+            Self {
+                sender: Sender::Bounded(tx),
+            },
+            ReceiverStream::new(rx),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateT, FwdPropsT> AgentSubscriber<StateT, FwdPropsT> for ChannelSubscriber<StateT>
+where
+    StateT: AgentState,
+    FwdPropsT: FwdProps,
+{
+    async fn on_event(
+        &self,
+        event: &Event<StateT>,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        // A dropped stream just means the consumer stopped listening -- not a run failure.
+        match &self.sender {
+            Sender::Unbounded(tx) => {
+                let _ = tx.send(event.clone());
+            }
+            Sender::Bounded(tx) => {
+                let _ = tx.send(event.clone()).await;
+            }
+        }
+        Ok(AgentStateMutation::default())
+    }
+}
+
+/// An item yielded by a [`FanOutSubscriber`] stream: either a forwarded event, or a signal that
+/// the receiver fell behind and missed some events.
+///
+/// Surfacing the lag as a stream item (rather than silently dropping it) gives a consumer a
+/// chance to react -- e.g. re-sync off the next `StateSnapshot`/`MessagesSnapshot` instead of
+/// working from a conversation it knows is now missing events.
+#[derive(Debug, Clone)]
+pub enum FanOutItem<StateT: AgentState = JsonValue> {
+    /// A forwarded event from the run.
+    Event(Event<StateT>),
+    /// The receiver fell behind by more than the channel's capacity and missed `skipped` events,
+    /// which are gone for good; the stream itself is still healthy and keeps yielding from where
+    /// it now stands.
+    Lagged {
+        /// How many events were dropped before this receiver could catch up.
+        skipped: u64,
+    },
+}
+
+/// An [`AgentSubscriber`] built on `tokio::sync::broadcast` so several independent consumers --
+/// a logger, a UI, metrics -- can each [`subscribe`](FanOutSubscriber::subscribe) their own
+/// `Stream` from a single run, mirroring the worker-channel pattern used for parent/child event
+/// propagation.
+///
+/// Each subscription only sees events broadcast after it was created; a slow subscriber that
+/// falls behind by more than `capacity` events misses the oldest ones rather than blocking the
+/// run, surfaced as a [`FanOutItem::Lagged`] item instead of silently dropped.
+#[derive(Clone)]
+pub struct FanOutSubscriber<StateT: AgentState = JsonValue> {
+    sender: broadcast::Sender<Event<StateT>>,
+}
+
+/// Alias for [`FanOutSubscriber`], for callers reaching for the more familiar "broadcast" name;
+/// see also [`Agent::run_agent_stream`](crate::agent::Agent::run_agent_stream), which wires one of
+/// these into a run automatically.
+pub type BroadcastSubscriber<StateT = JsonValue> = FanOutSubscriber<StateT>;
+
+impl<StateT: AgentState> FanOutSubscriber<StateT> {
+    /// Creates a fan-out subscriber with the given per-receiver buffer `capacity`, returning it
+    /// alongside one initial subscription stream.
+    pub fn new(capacity: usize) -> (Self, impl Stream<Item = FanOutItem<StateT>> + Unpin) {
+        let (sender, rx) = broadcast::channel(capacity);
+        let subscriber = Self { sender };
+        let stream = Self::stream_from(rx);
+        (subscriber, stream)
+    }
+
+    /// Subscribes another independent consumer; dropping the returned stream doesn't affect any
+    /// other subscriber or the run itself.
+    pub fn subscribe(&self) -> impl Stream<Item = FanOutItem<StateT>> + Unpin {
+        Self::stream_from(self.sender.subscribe())
+    }
+
+    fn stream_from(
+        rx: broadcast::Receiver<Event<StateT>>,
+    ) -> impl Stream<Item = FanOutItem<StateT>> + Unpin {
+        BroadcastStream::new(rx).map(|item| match item {
+            Ok(event) => FanOutItem::Event(event),
+            // Recoverable: the receiver fell behind and missed some events, but the stream
+            // itself is still healthy, so log it and surface it to the caller instead of
+            // ending the stream.
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                warn!("FanOutSubscriber receiver lagged behind the run, {skipped} event(s) dropped");
+                FanOutItem::Lagged { skipped }
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateT, FwdPropsT> AgentSubscriber<StateT, FwdPropsT> for FanOutSubscriber<StateT>
+where
+    StateT: AgentState,
+    FwdPropsT: FwdProps,
+{
+    async fn on_event(
+        &self,
+        event: &Event<StateT>,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        // No subscribers yet (or all dropped) is fine -- the event is simply not observed.
+        let _ = self.sender.send(event.clone());
+        Ok(AgentStateMutation::default())
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::{BufRead, Write};
+
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::core::event::Event;
+use crate::core::types::{RunId, ThreadId};
+use crate::core::AgentState;
+use crate::reducer::{EventReducer, ReduceError};
+
+/// Errors encoding or decoding an [`Envelope`].
+#[derive(Debug, thiserror::Error)]
+pub enum EnvelopeError {
+    /// The underlying writer/reader failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The header line didn't parse as an [`EnvelopeHeader`].
+    #[error("failed to decode envelope header: {0}")]
+    Header(serde_json::Error),
+
+    /// An event failed to encode to JSON.
+    #[error("failed to encode an envelope entry: {0}")]
+    Encode(serde_json::Error),
+
+    /// A line in the body didn't parse as a type-tagged `Event`.
+    #[error("failed to decode event on line {line}: {source}")]
+    Event {
+        line: usize,
+        source: serde_json::Error,
+    },
+
+    /// [`Envelope::from_stream`] reached the end of the stream without ever observing a
+    /// `RunStarted` event to anchor the envelope's header.
+    #[error("stream ended before a RunStarted event was observed")]
+    MissingRunStarted,
+}
+
+/// The leading header line of a recorded [`Envelope`], identifying which run its events belong
+/// to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvelopeHeader {
+    #[serde(rename = "threadId")]
+    pub thread_id: ThreadId,
+    #[serde(rename = "runId")]
+    pub run_id: RunId,
+}
+
+/// A durable, on-disk recording of one agent run: a leading JSON header line identifying the
+/// thread/run, followed by one compact `Event` JSON object per line.
+///
+/// This mirrors how observability envelopes bundle a header plus heterogeneous items, and lets
+/// an entire run be persisted, shipped elsewhere, and later replayed through an [`EventReducer`]
+/// to deterministically rebuild its messages and state -- useful for debugging a production run
+/// offline or pinning one down as a regression test fixture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Envelope<StateT: AgentState> {
+    pub header: EnvelopeHeader,
+    pub events: Vec<Event<StateT>>,
+}
+
+impl<StateT: AgentState> Envelope<StateT> {
+    pub fn new(header: EnvelopeHeader, events: Vec<Event<StateT>>) -> Self {
+        Self { header, events }
+    }
+
+    /// Writes the header line followed by one line per event.
+    pub fn to_writer<W: Write>(&self, mut writer: W) -> Result<(), EnvelopeError> {
+        let header_line = serde_json::to_string(&self.header).map_err(EnvelopeError::Encode)?;
+        writeln!(writer, "{header_line}")?;
+
+        for event in &self.events {
+            let line = serde_json::to_string(event).map_err(EnvelopeError::Encode)?;
+            writeln!(writer, "{line}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a header line followed by one event per remaining non-blank line.
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, EnvelopeError> {
+        let mut header_line = String::new();
+        reader.read_line(&mut header_line)?;
+        let header: EnvelopeHeader =
+            serde_json::from_str(header_line.trim_end()).map_err(EnvelopeError::Header)?;
+
+        let mut events = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event = serde_json::from_str(&line).map_err(|source| EnvelopeError::Event {
+                line: i + 2,
+                source,
+            })?;
+            events.push(event);
+        }
+
+        Ok(Self { header, events })
+    }
+
+    /// Drains a live event stream (e.g. an [`crate::agent::Agent::run`] result) into an
+    /// envelope: collection starts at the first `RunStarted`, which also supplies the header,
+    /// and stops right after the first `RunFinished`/`RunError`. Events observed before a
+    /// `RunStarted` arrives are discarded.
+    pub async fn from_stream(
+        mut events: impl Stream<Item = Event<StateT>> + Unpin,
+    ) -> Result<Self, EnvelopeError> {
+        let mut header = None;
+        let mut collected = Vec::new();
+
+        while let Some(event) = events.next().await {
+            if header.is_none() {
+                if let Event::RunStarted(e) = &event {
+                    header = Some(EnvelopeHeader {
+                        thread_id: e.thread_id.clone(),
+                        run_id: e.run_id.clone(),
+                    });
+                } else {
+                    continue;
+                }
+            }
+
+            let is_terminal = matches!(event, Event::RunFinished(_) | Event::RunError(_));
+            collected.push(event);
+            if is_terminal {
+                break;
+            }
+        }
+
+        let header = header.ok_or(EnvelopeError::MissingRunStarted)?;
+        Ok(Self {
+            header,
+            events: collected,
+        })
+    }
+
+    /// Replays every event in this envelope through a fresh [`EventReducer`], rebuilding the
+    /// final messages and state deterministically.
+    pub fn replay(&self) -> Result<EventReducer<StateT>, ReduceError> {
+        let mut reducer = EventReducer::new();
+        for event in &self.events {
+            reducer.apply(event)?;
+        }
+        Ok(reducer)
+    }
+}
@@ -1,4 +1,6 @@
 use reqwest::StatusCode;
+use std::collections::HashSet;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Ag-ui client errors
@@ -18,8 +20,32 @@ pub enum AgUiClientError {
     HttpStatus {
         status: reqwest::StatusCode,
         context: String,
+        /// The server's `Retry-After` hint, if it sent one (delta-seconds or HTTP-date form),
+        /// for a caller that wants to honor it instead of its own backoff schedule.
+        retry_after: Option<Duration>,
     },
 
+    /// Non-success HTTP status whose body parsed as a structured AG-UI error payload (a JSON
+    /// object with at least a `message` or `code` field).
+    #[error("server error {status} ({code}): {message}")]
+    ServerError {
+        status: reqwest::StatusCode,
+        code: String,
+        message: String,
+        details: Option<serde_json::Value>,
+        /// The response body as captured (capped by `HttpAgentBuilder::with_max_error_body_bytes`),
+        /// for callers who need to log the raw diagnostic.
+        raw_body: String,
+        /// The server's `Retry-After` hint, if it sent one (delta-seconds or HTTP-date form),
+        /// for a caller that wants to honor it instead of its own backoff schedule.
+        retry_after: Option<Duration>,
+    },
+
+    /// Transport failures from non-`reqwest` connections (e.g. Unix domain sockets), where
+    /// there's no `reqwest::Error` to wrap.
+    #[error("transport error: {message}")]
+    Transport { message: String },
+
     /// SSE parsing/framing/UTF-8 errors
     #[error("SSE parse error: {message}")]
     SseParse { message: String },
@@ -35,6 +61,13 @@ pub enum AgUiClientError {
     /// Pipeline catch-all
     #[error("Agent execution error: {message}")]
     Execution { message: String },
+
+    /// A configured [`crate::http::HttpAgentBuilder::with_timeout`]/
+    /// [`with_connect_timeout`](crate::http::HttpAgentBuilder::with_connect_timeout) elapsed
+    /// before the run completed, surfaced distinctly from [`Self::HttpTransport`] so application
+    /// code can tell a bounded-time cutoff apart from a genuine protocol/connection failure.
+    #[error("request timed out: {message}")]
+    Timeout { message: String },
 }
 
 impl AgUiClientError {
@@ -45,18 +78,42 @@ impl AgUiClientError {
         Self::Execution { message: m.into() }
     }
 
+    /// Whether or not the error is retryable, using [`RateLimitMatcher::default`] to classify
+    /// [`ServerError`](AgUiClientError::ServerError) bodies. See [`Self::is_retryable_with`] to
+    /// tune the code/message matching for a specific provider.
+    pub fn is_retryable(&self) -> bool {
+        self.is_retryable_with(&RateLimitMatcher::default())
+    }
+
     /// Whether or not the error is retryable.
+    ///
     /// Generally, the request is considered retryable if the following errors are received:
     /// - Connection errors
     /// - Timeout errors
     /// - Internal server errors
     /// - Errors related to too many requests (ie, rate limiting or throttling)
-    pub fn is_retryable(&self) -> bool {
+    ///
+    /// Some agent backends report a rate-limit/capacity error as a `200` or a `4xx` wrapping a
+    /// JSON error envelope rather than a `429`/`5xx` status, so a
+    /// [`ServerError`](AgUiClientError::ServerError) is additionally checked against `matcher`:
+    /// its (string) `code`, parsed as an integer, and its `message` text.
+    pub fn is_retryable_with(&self, matcher: &RateLimitMatcher) -> bool {
         match self {
             AgUiClientError::HttpTransport(e) => e.is_connect() || e.is_timeout() || e.is_request(),
+            AgUiClientError::Timeout { .. } => true,
             AgUiClientError::HttpStatus { status, .. } => {
                 status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
             }
+            AgUiClientError::ServerError {
+                status,
+                code,
+                message,
+                ..
+            } => {
+                status.is_server_error()
+                    || *status == StatusCode::TOO_MANY_REQUESTS
+                    || matcher.matches(code.parse().ok(), message)
+            }
             _ => false,
         }
     }
@@ -64,6 +121,272 @@ impl AgUiClientError {
     pub fn is_user_input(&self) -> bool {
         matches!(self, AgUiClientError::Config { .. })
     }
+
+    /// Builds the appropriate error variant for a non-success HTTP response body: a structured
+    /// [`ServerError`](AgUiClientError::ServerError) when the body parses as a JSON object with a
+    /// `message` and/or `code` field, otherwise the flat [`HttpStatus`](AgUiClientError::HttpStatus)
+    /// with the body kept verbatim as context. `retry_after` carries through the server's parsed
+    /// `Retry-After` hint, if any, so a caller can prefer it over its own backoff schedule.
+    pub fn from_response_body(status: StatusCode, body: String, retry_after: Option<Duration>) -> Self {
+        let Ok(serde_json::Value::Object(map)) = serde_json::from_str::<serde_json::Value>(&body) else {
+            return AgUiClientError::HttpStatus { status, context: body, retry_after };
+        };
+
+        let message = map.get("message").and_then(|v| v.as_str());
+        // Accept a numeric `code` (e.g. `{"code": 42}`) as well as a string one, so a provider
+        // that reports codes as JSON numbers still gets picked up by `RateLimitMatcher`.
+        let code = map.get("code").and_then(|v| match v {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+        if message.is_none() && code.is_none() {
+            return AgUiClientError::HttpStatus { status, context: body, retry_after };
+        }
+
+        AgUiClientError::ServerError {
+            status,
+            code: code.unwrap_or_default(),
+            message: message
+                .map(str::to_string)
+                .unwrap_or_else(|| status.canonical_reason().unwrap_or("unknown error").to_string()),
+            details: map.get("details").cloned(),
+            raw_body: body,
+            retry_after,
+        }
+    }
+}
+
+/// The numeric codes and case-insensitive message substrings [`AgUiClientError::is_retryable_with`]
+/// treats as a transient rate-limit/capacity error in a
+/// [`ServerError`](AgUiClientError::ServerError) body, even when the HTTP status itself isn't one
+/// of the usual retryable ones (a backend may report throttling as a `200` or a `4xx`).
+///
+/// The default substrings ("rate limit", "throttl", "capacity", "too many requests") cover common
+/// phrasing; `codes` is empty by default since provider-specific error codes vary. Construct a
+/// custom instance to tune either for your own provider, similar to how alloy's `is_retry_err`
+/// inspects both code and message text.
+#[derive(Debug, Clone)]
+pub struct RateLimitMatcher {
+    pub codes: HashSet<i64>,
+    pub message_substrings: Vec<String>,
+}
+
+impl RateLimitMatcher {
+    pub fn new(codes: impl IntoIterator<Item = i64>, message_substrings: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            codes: codes.into_iter().collect(),
+            message_substrings: message_substrings.into_iter().collect(),
+        }
+    }
+
+    /// Whether `code` is in [`Self::codes`] or `message` contains one of
+    /// [`Self::message_substrings`] (case-insensitive).
+    pub fn matches(&self, code: Option<i64>, message: &str) -> bool {
+        if code.is_some_and(|code| self.codes.contains(&code)) {
+            return true;
+        }
+        let message = message.to_lowercase();
+        self.message_substrings
+            .iter()
+            .any(|substring| message.contains(&substring.to_lowercase()))
+    }
+}
+
+impl Default for RateLimitMatcher {
+    fn default() -> Self {
+        Self {
+            codes: HashSet::new(),
+            message_substrings: vec![
+                "rate limit".to_string(),
+                "throttl".to_string(),
+                "capacity".to_string(),
+                "too many requests".to_string(),
+            ],
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, AgUiClientError>;
+
+/// The result of a single request attempt, before any retry/fail decision has been made.
+///
+/// This is deliberately lower-level than [`AgUiClientError`]: a [`RetryLogic`] needs the raw
+/// status/body or transport error to decide whether the failure is transient, rather than a
+/// message that has already been formatted for display.
+#[derive(Debug)]
+pub enum RetryOutcome {
+    /// The server responded with a non-success HTTP status.
+    Status {
+        status: StatusCode,
+        /// The response body, capped to the caller's configured size limit.
+        body: String,
+        /// The parsed `Retry-After` header, if the server sent one (delta-seconds or HTTP-date form).
+        retry_after: Option<Duration>,
+    },
+    /// The request failed at the transport layer (connect, timeout, etc.) before a status was
+    /// received.
+    Transport(reqwest::Error),
+}
+
+impl RetryOutcome {
+    /// Convert this outcome into the [`AgUiClientError`] that should surface when a
+    /// [`RetryLogic`] decides not to retry it.
+    pub fn into_error(self) -> AgUiClientError {
+        match self {
+            RetryOutcome::Status {
+                status,
+                body,
+                retry_after,
+            } => AgUiClientError::from_response_body(status, body, retry_after),
+            RetryOutcome::Transport(e) if e.is_timeout() => AgUiClientError::Timeout {
+                message: e.to_string(),
+            },
+            RetryOutcome::Transport(e) => AgUiClientError::from(e),
+        }
+    }
+}
+
+/// What a [`RetryLogic`] decided to do about a [`RetryOutcome`].
+#[derive(Debug)]
+pub enum RetryAction {
+    /// Retry after waiting out the caller's own backoff schedule.
+    Retry,
+    /// Retry after waiting (at least) the given duration, e.g. to honor a `Retry-After` header.
+    RetryAfter(Duration),
+    /// Give up and surface the outcome as the default [`AgUiClientError`].
+    DontRetry,
+    /// Give up and surface this specific error instead of the default one.
+    Fail(AgUiClientError),
+}
+
+/// Classifies whether a failed run attempt should be retried.
+///
+/// Implement this to customize retry behavior beyond the default "retry on connect/timeout/request
+/// transport errors and 408/429/502/503/504" policy -- for example, to also retry a provider-specific error code, or
+/// to never retry non-idempotent operations.
+pub trait RetryLogic: Send + Sync {
+    fn classify(&self, outcome: &RetryOutcome) -> RetryAction;
+}
+
+/// The default [`RetryLogic`]: retries transport errors that `reqwest` reports as connect,
+/// timeout or request errors, and HTTP
+/// 408/429/502/503/504, honoring a `Retry-After` header when the server sent one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultRetryLogic;
+
+impl RetryLogic for DefaultRetryLogic {
+    fn classify(&self, outcome: &RetryOutcome) -> RetryAction {
+        match outcome {
+            RetryOutcome::Status {
+                status,
+                retry_after,
+                ..
+            } => {
+                let retriable = matches!(
+                    *status,
+                    StatusCode::REQUEST_TIMEOUT
+                        | StatusCode::TOO_MANY_REQUESTS
+                        | StatusCode::BAD_GATEWAY
+                        | StatusCode::SERVICE_UNAVAILABLE
+                        | StatusCode::GATEWAY_TIMEOUT
+                );
+                if !retriable {
+                    return RetryAction::DontRetry;
+                }
+                match retry_after {
+                    Some(delay) => RetryAction::RetryAfter(*delay),
+                    None => RetryAction::Retry,
+                }
+            }
+            RetryOutcome::Transport(e) => {
+                if e.is_connect() || e.is_timeout() || e.is_request() {
+                    RetryAction::Retry
+                } else {
+                    RetryAction::DontRetry
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_response_body_parses_structured_json_error() {
+        let body = r#"{"message": "invalid run input", "code": "bad_input", "details": {"field": "thread_id"}}"#;
+        let err = AgUiClientError::from_response_body(StatusCode::BAD_REQUEST, body.to_string(), None);
+        match err {
+            AgUiClientError::ServerError {
+                status,
+                code,
+                message,
+                details,
+                raw_body,
+                retry_after,
+            } => {
+                assert_eq!(status, StatusCode::BAD_REQUEST);
+                assert_eq!(code, "bad_input");
+                assert_eq!(message, "invalid run input");
+                assert_eq!(details, Some(serde_json::json!({"field": "thread_id"})));
+                assert_eq!(raw_body, body);
+                assert_eq!(retry_after, None);
+            }
+            other => panic!("expected ServerError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_response_body_falls_back_to_http_status_for_non_json() {
+        let err = AgUiClientError::from_response_body(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "oops, server exploded".to_string(),
+            Some(Duration::from_secs(30)),
+        );
+        match err {
+            AgUiClientError::HttpStatus {
+                status,
+                context,
+                retry_after,
+            } => {
+                assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+                assert_eq!(context, "oops, server exploded");
+                assert_eq!(retry_after, Some(Duration::from_secs(30)));
+            }
+            other => panic!("expected HttpStatus, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_response_body_falls_back_for_json_without_message_or_code() {
+        let err = AgUiClientError::from_response_body(StatusCode::NOT_FOUND, r#"{"unrelated": true}"#.to_string(), None);
+        assert!(matches!(err, AgUiClientError::HttpStatus { .. }));
+    }
+
+    #[test]
+    fn from_response_body_stringifies_a_numeric_code() {
+        let body = r#"{"message": "over quota", "code": 1015}"#;
+        let err = AgUiClientError::from_response_body(StatusCode::TOO_MANY_REQUESTS, body.to_string(), None);
+        match err {
+            AgUiClientError::ServerError { code, .. } => assert_eq!(code, "1015"),
+            other => panic!("expected ServerError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn is_retryable_with_matches_a_rate_limit_code_on_a_non_retryable_status() {
+        let err = AgUiClientError::ServerError {
+            status: StatusCode::BAD_REQUEST,
+            code: "1015".to_string(),
+            message: "over quota".to_string(),
+            details: None,
+            raw_body: "{}".to_string(),
+            retry_after: None,
+        };
+        assert!(!err.is_retryable());
+        let matcher = RateLimitMatcher::new([1015], []);
+        assert!(err.is_retryable_with(&matcher));
+    }
+}
@@ -1,9 +1,10 @@
 use crate::agent::{AgentError, AgentStateMutation};
-use crate::core::event::Event;
-use crate::core::types::{FunctionCall, Message, MessageId, Role, RunAgentInput, ToolCall};
+use crate::core::event::{Event, EventType};
+use crate::core::types::{
+    FunctionCall, Message, MessageContent, MessageId, Role, RunAgentInput, ToolCall,
+};
 use crate::core::{AgentState, FwdProps, JsonValue};
 use crate::subscriber::{AgentSubscriberParams, Subscribers};
-use json_patch::PatchOperation;
 use log::error;
 use std::collections::{HashMap, HashSet};
 
@@ -86,9 +87,13 @@ where
     ) -> Result<AgentStateMutation<StateT>, AgentError> {
         let mut current_mutation = AgentStateMutation::default();
         let mut mutations = Vec::new();
+        let event_type = event.event_type();
 
         // Clone subscribers to avoid borrowing issues
         for subscriber in &self.subscribers.clone() {
+            if !subscriber.interests().contains(event_type) {
+                continue;
+            }
             let params = self.to_subscriber_params();
             let mutation = subscriber.on_event(event, params).await?;
             mutations.push(mutation);
@@ -100,7 +105,7 @@ where
                 // Default behavior
                 let new_message = Message::Assistant {
                     id: e.message_id.clone(),
-                    content: Some(String::new()),
+                    content: Some(MessageContent::default()),
                     name: None,
                     tool_calls: None,
                 };
@@ -108,6 +113,9 @@ where
                 current_mutation.messages = Some(self.messages.clone());
 
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::TextMessageStart) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_text_message_start_event(e, params).await?;
                     mutations.push(mutation);
@@ -123,41 +131,54 @@ where
                     current_mutation.messages = Some(self.messages.clone());
                 }
 
-                // Get the current text message buffer
-                let text_message_buffer = self
-                    .messages
-                    .last()
-                    .and_then(|m| m.content())
-                    .unwrap_or_default()
-                    .to_string(); // Clone to avoid borrowing issues
-
-                for subscriber in &self.subscribers {
-                    let params = self.to_subscriber_params();
-                    let mutation = subscriber
-                        .on_text_message_content_event(e, &text_message_buffer, params)
-                        .await?;
-                    mutations.push(mutation);
+                // Only worth maintaining the buffer if some subscriber actually wants it.
+                if self.subscribers.interests().contains(EventType::TextMessageContent) {
+                    let text_message_buffer = self
+                        .messages
+                        .last()
+                        .and_then(|m| m.content())
+                        .unwrap_or_default()
+                        .to_string(); // Clone to avoid borrowing issues
+
+                    for subscriber in &self.subscribers {
+                        if !subscriber.interests().contains(EventType::TextMessageContent) {
+                            continue;
+                        }
+                        let params = self.to_subscriber_params();
+                        let mutation = subscriber
+                            .on_text_message_content_event(e, &text_message_buffer, params)
+                            .await?;
+                        mutations.push(mutation);
+                    }
                 }
             }
             Event::TextMessageEnd(e) => {
-                // Get the current text message buffer
-                let text_message_buffer = self
-                    .messages
-                    .last()
-                    .and_then(|m| m.content())
-                    .unwrap_or_default()
-                    .to_string(); // Clone to avoid borrowing issues
-
-                for subscriber in &self.subscribers {
-                    let params = self.to_subscriber_params();
-                    let mutation = subscriber
-                        .on_text_message_end_event(e, &text_message_buffer, params)
-                        .await?;
-                    mutations.push(mutation);
+                // Only worth maintaining the buffer if some subscriber actually wants it.
+                if self.subscribers.interests().contains(EventType::TextMessageEnd) {
+                    let text_message_buffer = self
+                        .messages
+                        .last()
+                        .and_then(|m| m.content())
+                        .unwrap_or_default()
+                        .to_string(); // Clone to avoid borrowing issues
+
+                    for subscriber in &self.subscribers {
+                        if !subscriber.interests().contains(EventType::TextMessageEnd) {
+                            continue;
+                        }
+                        let params = self.to_subscriber_params();
+                        let mutation = subscriber
+                            .on_text_message_end_event(e, &text_message_buffer, params)
+                            .await?;
+                        mutations.push(mutation);
+                    }
                 }
             }
             Event::TextMessageChunk(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::TextMessageChunk) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_text_message_chunk_event(e, params).await?;
                     mutations.push(mutation);
@@ -165,6 +186,9 @@ where
             }
             Event::ThinkingTextMessageStart(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ThinkingTextMessageStart) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber
                         .on_thinking_text_message_start_event(e, params)
@@ -174,6 +198,9 @@ where
             }
             Event::ThinkingTextMessageContent(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ThinkingTextMessageContent) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber
                         .on_thinking_text_message_content_event(e, params)
@@ -183,6 +210,9 @@ where
             }
             Event::ThinkingTextMessageEnd(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ThinkingTextMessageEnd) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber
                         .on_thinking_text_message_end_event(e, params)
@@ -224,6 +254,9 @@ where
                 current_mutation.messages = Some(self.messages.clone());
 
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ToolCallStart) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_tool_call_start_event(e, params).await?;
                     mutations.push(mutation);
@@ -239,78 +272,93 @@ where
                     current_mutation.messages = Some(self.messages.clone());
                 }
 
-                // Get the current tool call buffer and name
-                let (tool_call_buffer, tool_call_name, partial_args) = if let Some(last_message) =
-                    self.messages.last()
-                {
-                    if let Some(tool_calls) = last_message.tool_calls() {
-                        if let Some(last_tool_call) = tool_calls.last() {
-                            // Try to parse the arguments as JSON to get partial args
-                            let partial_args = serde_json::from_str::<HashMap<String, JsonValue>>(
-                                &last_tool_call.function.arguments,
-                            )
-                            .unwrap_or_default();
-                            (
-                                last_tool_call.function.arguments.clone(),
-                                last_tool_call.function.name.clone(),
-                                partial_args,
-                            )
+                // Only worth parsing out the buffer/partial-args if some subscriber wants them.
+                if self.subscribers.interests().contains(EventType::ToolCallArgs) {
+                    // Get the current tool call buffer and name
+                    let (tool_call_buffer, tool_call_name, partial_args) = if let Some(last_message) =
+                        self.messages.last()
+                    {
+                        if let Some(tool_calls) = last_message.tool_calls() {
+                            if let Some(last_tool_call) = tool_calls.last() {
+                                // Try to parse the arguments as JSON to get partial args
+                                let partial_args = serde_json::from_str::<HashMap<String, JsonValue>>(
+                                    &last_tool_call.function.arguments,
+                                )
+                                .unwrap_or_default();
+                                (
+                                    last_tool_call.function.arguments.clone(),
+                                    last_tool_call.function.name.clone(),
+                                    partial_args,
+                                )
+                            } else {
+                                (String::new(), String::new(), HashMap::new())
+                            }
                         } else {
                             (String::new(), String::new(), HashMap::new())
                         }
                     } else {
                         (String::new(), String::new(), HashMap::new())
-                    }
-                } else {
-                    (String::new(), String::new(), HashMap::new())
-                };
+                    };
 
-                for subscriber in &self.subscribers {
-                    let params = self.to_subscriber_params();
-                    let mutation = subscriber
-                        .on_tool_call_args_event(
-                            e,
-                            &tool_call_buffer,
-                            &tool_call_name,
-                            &partial_args,
-                            params,
-                        )
-                        .await?;
-                    mutations.push(mutation);
+                    for subscriber in &self.subscribers {
+                        if !subscriber.interests().contains(EventType::ToolCallArgs) {
+                            continue;
+                        }
+                        let params = self.to_subscriber_params();
+                        let mutation = subscriber
+                            .on_tool_call_args_event(
+                                e,
+                                &tool_call_buffer,
+                                &tool_call_name,
+                                &partial_args,
+                                params,
+                            )
+                            .await?;
+                        mutations.push(mutation);
+                    }
                 }
             }
             Event::ToolCallEnd(e) => {
-                // Get the current tool call buffer and name
-                let (tool_call_name, tool_call_args) =
-                    if let Some(last_message) = self.messages.last() {
-                        if let Some(tool_calls) = last_message.tool_calls() {
-                            if let Some(last_tool_call) = tool_calls.last() {
-                                // Try to parse the arguments as JSON
-                                let args = serde_json::from_str::<HashMap<String, JsonValue>>(
-                                    &last_tool_call.function.arguments,
-                                )
-                                .unwrap_or_default();
-                                (last_tool_call.function.name.clone(), args)
+                // Only worth parsing out the buffer/name if some subscriber wants them.
+                if self.subscribers.interests().contains(EventType::ToolCallEnd) {
+                    // Get the current tool call buffer and name
+                    let (tool_call_name, tool_call_args) =
+                        if let Some(last_message) = self.messages.last() {
+                            if let Some(tool_calls) = last_message.tool_calls() {
+                                if let Some(last_tool_call) = tool_calls.last() {
+                                    // Try to parse the arguments as JSON
+                                    let args = serde_json::from_str::<HashMap<String, JsonValue>>(
+                                        &last_tool_call.function.arguments,
+                                    )
+                                    .unwrap_or_default();
+                                    (last_tool_call.function.name.clone(), args)
+                                } else {
+                                    (String::new(), HashMap::new())
+                                }
                             } else {
                                 (String::new(), HashMap::new())
                             }
                         } else {
                             (String::new(), HashMap::new())
-                        }
-                    } else {
-                        (String::new(), HashMap::new())
-                    };
+                        };
 
-                for subscriber in &self.subscribers {
-                    let params = self.to_subscriber_params();
-                    let mutation = subscriber
-                        .on_tool_call_end_event(e, &tool_call_name, &tool_call_args, params)
-                        .await?;
-                    mutations.push(mutation);
+                    for subscriber in &self.subscribers {
+                        if !subscriber.interests().contains(EventType::ToolCallEnd) {
+                            continue;
+                        }
+                        let params = self.to_subscriber_params();
+                        let mutation = subscriber
+                            .on_tool_call_end_event(e, &tool_call_name, &tool_call_args, params)
+                            .await?;
+                        mutations.push(mutation);
+                    }
                 }
             }
             Event::ToolCallChunk(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ToolCallChunk) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_tool_call_chunk_event(e, params).await?;
                     mutations.push(mutation);
@@ -318,6 +366,9 @@ where
             }
             Event::ToolCallResult(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ToolCallResult) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_tool_call_result_event(e, params).await?;
                     mutations.push(mutation);
@@ -325,6 +376,9 @@ where
             }
             Event::ThinkingStart(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ThinkingStart) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_thinking_start_event(e, params).await?;
                     mutations.push(mutation);
@@ -332,6 +386,9 @@ where
             }
             Event::ThinkingEnd(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::ThinkingEnd) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_thinking_end_event(e, params).await?;
                     mutations.push(mutation);
@@ -343,6 +400,9 @@ where
                 current_mutation.state = Some(self.state.clone());
 
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::StateSnapshot) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_state_snapshot_event(e, params).await?;
                     mutations.push(mutation);
@@ -350,22 +410,17 @@ where
             }
             Event::StateDelta(e) => {
                 // Default behavior
-                let mut state_val = serde_json::to_value(&self.state)?;
-
-                // TODO: This cast to and from JsonValue seems unnecessary
-                let patches: Vec<PatchOperation> =
-                    serde_json::from_value(serde_json::to_value(e.delta.clone())?)?;
-
-                json_patch::patch(&mut state_val, &patches).map_err(|err| {
+                self.state = crate::core::apply_state_delta(&self.state, &e.delta).map_err(|err| {
                     AgentError::Execution {
                         message: format!("Failed to apply state patch: {err}"),
                     }
                 })?;
-                let new_state: StateT = serde_json::from_value(state_val)?;
-                self.state = new_state;
                 current_mutation.state = Some(self.state.clone());
 
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::StateDelta) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_state_delta_event(e, params).await?;
                     mutations.push(mutation);
@@ -373,6 +428,9 @@ where
             }
             Event::MessagesSnapshot(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::MessagesSnapshot) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_messages_snapshot_event(e, params).await?;
                     mutations.push(mutation);
@@ -380,6 +438,9 @@ where
             }
             Event::Raw(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::Raw) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_raw_event(e, params).await?;
                     mutations.push(mutation);
@@ -387,6 +448,9 @@ where
             }
             Event::Custom(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::Custom) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_custom_event(e, params).await?;
                     mutations.push(mutation);
@@ -394,6 +458,9 @@ where
             }
             Event::RunStarted(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::RunStarted) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_run_started_event(e, params).await?;
                     mutations.push(mutation);
@@ -404,6 +471,9 @@ where
                 self.result = e.result.clone().unwrap_or(JsonValue::Null);
 
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::RunFinished) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_run_finished_event(e, params).await?;
                     mutations.push(mutation);
@@ -411,6 +481,9 @@ where
             }
             Event::RunError(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::RunError) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_run_error_event(e, params).await?;
                     mutations.push(mutation);
@@ -418,6 +491,9 @@ where
             }
             Event::StepStarted(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::StepStarted) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_step_started_event(e, params).await?;
                     mutations.push(mutation);
@@ -425,6 +501,9 @@ where
             }
             Event::StepFinished(e) => {
                 for subscriber in &self.subscribers {
+                    if !subscriber.interests().contains(EventType::StepFinished) {
+                        continue;
+                    }
                     let params = self.to_subscriber_params();
                     let mutation = subscriber.on_step_finished_event(e, params).await?;
                     mutations.push(mutation);
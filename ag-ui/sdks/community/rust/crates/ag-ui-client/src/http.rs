@@ -1,24 +1,90 @@
 use crate::Agent;
-use crate::agent::AgentError;
+use crate::agent::{AgentError, RunAgentParams, RunAgentResult, TransportRetryEvent};
+use crate::core::EncodingType;
 use crate::core::event::Event;
-use crate::core::types::RunAgentInput;
-use crate::core::{AgentState, FwdProps};
-use crate::sse::SseResponseExt;
+use crate::core::types::{AgentId, MessageId, RunAgentInput, RunId, ThreadId};
+use crate::core::{AgentState, FwdProps, base64_decode};
+use crate::error::{DefaultRetryLogic, RetryAction, RetryLogic, RetryOutcome};
+use crate::event_handler::EventHandler;
+use crate::rate_limiter::RateLimiter;
+use crate::sse::{ReconnectConfig, SseEvent, SseResponseExt, sse_event_stream};
 use crate::stream::EventStream;
-use ag_ui_core::types::AgentId;
+use crate::subscriber::{AgentSubscriberParams, IntoSubscribers};
+use crate::uds;
 use async_trait::async_trait;
-use futures::StreamExt;
+use futures::channel::mpsc;
+use futures::{Stream, StreamExt};
 use log::{debug, trace};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use reqwest::{Client as HttpClient, Url};
+use reqwest::{Certificate, Client as HttpClient, Identity, Url};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Controls how aggressively [`HttpAgent::run`] retries its initial POST before the SSE stream
+/// has yielded a first event, once [`RetryLogic`] has decided the failure is worth retrying.
+///
+/// Once an event has been received the run is no longer idempotent (a subscriber may already
+/// have observed partial output), so retries only ever apply to the connect/initial-response
+/// phase. Each [`RetryAction::Retry`] attempt waits `base_delay * 2^attempt`, capped at
+/// `max_delay`, with full jitter (a uniformly random duration between zero and that cap) to avoid
+/// synchronized retries across many clients; a [`RetryAction::RetryAfter`] instead waits exactly
+/// the duration the logic returned. Either way, `max_retries` bounds the number of retries, so a
+/// run makes at most `max_retries + 1` attempts in all.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    fn backoff_for(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.saturating_mul(1u32 << attempt.min(16));
+        exp.min(self.max_delay)
+    }
+}
+
+/// Default cap on how much of a non-success response body is captured for
+/// [`AgentError::HttpStatus`]/[`AgentError::ServerError`], see
+/// [`HttpAgentBuilder::with_max_error_body_bytes`].
+const DEFAULT_MAX_ERROR_BODY_BYTES: usize = 8192;
+
+/// No retries by default -- existing callers keep today's fail-fast behavior unless they opt in
+/// via [`HttpAgentBuilder::with_retry_policy`].
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
 
 /// Represents an agent that communicates primarily via HTTP.
+#[derive(Clone)]
 pub struct HttpAgent {
     http_client: HttpClient,
     base_url: Url,
     header_map: HeaderMap,
     agent_id: Option<AgentId>,
+    encoding: EncodingType,
+    retry_policy: RetryPolicy,
+    retry_logic: Arc<dyn RetryLogic>,
+    /// When set, `run` dispatches over this Unix domain socket instead of TCP; `http_client` is
+    /// unused in that case.
+    unix_socket: Option<PathBuf>,
+    max_error_body_bytes: usize,
+    /// When set, `run` reconnects a dropped SSE stream with a `Last-Event-ID` header instead of
+    /// ending the run; see [`HttpAgentBuilder::with_resumable_stream`].
+    resume: Option<ReconnectConfig>,
+    /// When set, awaited before every request dispatch (initial POST, retries, and SSE
+    /// reconnects); see [`HttpAgentBuilder::with_rate_limiter`].
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl HttpAgent {
@@ -32,6 +98,13 @@ impl HttpAgent {
             base_url,
             header_map,
             agent_id: None,
+            encoding: EncodingType::Json,
+            retry_policy: RetryPolicy::default(),
+            retry_logic: Arc::new(DefaultRetryLogic),
+            unix_socket: None,
+            max_error_body_bytes: DEFAULT_MAX_ERROR_BODY_BYTES,
+            resume: None,
+            rate_limiter: None,
         }
     }
 
@@ -45,6 +118,18 @@ pub struct HttpAgentBuilder {
     header_map: HeaderMap,
     http_client: Option<HttpClient>,
     agent_id: Option<AgentId>,
+    encoding: EncodingType,
+    retry_policy: RetryPolicy,
+    retry_logic: Arc<dyn RetryLogic>,
+    tls_root_certificate: Option<Certificate>,
+    client_identity: Option<Identity>,
+    danger_accept_invalid_certs: bool,
+    unix_socket: Option<PathBuf>,
+    max_error_body_bytes: usize,
+    resume: Option<ReconnectConfig>,
+    rate_limiter: Option<RateLimiter>,
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
 }
 
 impl HttpAgentBuilder {
@@ -54,6 +139,18 @@ impl HttpAgentBuilder {
             header_map: HeaderMap::new(),
             http_client: None,
             agent_id: None,
+            encoding: EncodingType::Json,
+            retry_policy: RetryPolicy::default(),
+            retry_logic: Arc::new(DefaultRetryLogic),
+            tls_root_certificate: None,
+            client_identity: None,
+            danger_accept_invalid_certs: false,
+            unix_socket: None,
+            max_error_body_bytes: DEFAULT_MAX_ERROR_BODY_BYTES,
+            resume: None,
+            rate_limiter: None,
+            request_timeout: None,
+            connect_timeout: None,
         }
     }
 
@@ -108,13 +205,23 @@ impl HttpAgentBuilder {
         self
     }
 
-    /// Set request timeout in seconds
-    pub fn with_timeout(mut self, timeout_secs: u64) -> Self {
-        let client = HttpClient::builder()
-            .timeout(std::time::Duration::from_secs(timeout_secs))
-            .build()
-            .unwrap_or_else(|_| HttpClient::new());
-        self.http_client = Some(client);
+    /// Cap the overall time a run may take: once `timeout` elapses mid-request or mid-stream,
+    /// the run ends with [`AgentError::Timeout`] instead of hanging indefinitely.
+    ///
+    /// Cannot be combined with [`Self::with_http_client`], which hands over a client we can no
+    /// longer configure; set the timeout on that client directly instead.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Cap how long connecting to the server may take, separately from
+    /// [`Self::with_timeout`]'s budget for the whole run.
+    ///
+    /// Cannot be combined with [`Self::with_http_client`], which hands over a client we can no
+    /// longer configure; set the connect timeout on that client directly instead.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
         self
     }
 
@@ -124,25 +231,191 @@ impl HttpAgentBuilder {
         self
     }
 
+    /// Negotiate a specific wire encoding (JSON, MessagePack, or bincode) with the agent.
+    ///
+    /// The chosen encoding is sent as the request's `Content-Type`/`Accept` headers; the
+    /// response's actual `Content-Type` still wins when parsing the SSE stream, so a server
+    /// that ignores `Accept` and always replies with JSON keeps working.
+    pub fn with_encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Retry the initial POST up to `policy.max_retries` times on a retriable transport error or
+    /// status code, as long as it happens before the SSE stream has yielded a first event.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Swap in a custom [`RetryLogic`] to decide which failed attempts are retriable, instead of
+    /// [`DefaultRetryLogic`]'s "connect/timeout and 408/429/502/503/504" rule.
+    ///
+    /// The [`RetryPolicy`] set via [`with_retry_policy`](Self::with_retry_policy) still governs
+    /// `max_retries` and the backoff schedule for [`RetryAction::Retry`]; this only changes which
+    /// failures are eligible.
+    pub fn with_retry_logic(mut self, retry_logic: impl RetryLogic + 'static) -> Self {
+        self.retry_logic = Arc::new(retry_logic);
+        self
+    }
+
+    /// Trust an additional root certificate when validating the server's TLS chain, e.g. to
+    /// connect to an AG-UI server behind a private CA.
+    ///
+    /// Rejected at [`build`](Self::build) if combined with
+    /// [`with_http_client`](Self::with_http_client), which hands over a client we can no longer
+    /// configure; ignored when [`with_unix_socket`](Self::with_unix_socket) is set.
+    pub fn with_tls_root_certificate(mut self, cert: Certificate) -> Self {
+        self.tls_root_certificate = Some(cert);
+        self
+    }
+
+    /// Present a client certificate (mutual TLS) when connecting, for AG-UI deployments that
+    /// authenticate clients by certificate rather than (or in addition to) a bearer token.
+    pub fn with_client_identity(mut self, identity: Identity) -> Self {
+        self.client_identity = Some(identity);
+        self
+    }
+
+    /// Disable TLS certificate validation entirely.
+    ///
+    /// Dangerous: only use this against known test/staging endpoints with self-signed
+    /// certificates, never in production.
+    pub fn with_danger_accept_invalid_certs(mut self, accept_invalid: bool) -> Self {
+        self.danger_accept_invalid_certs = accept_invalid;
+        self
+    }
+
+    /// Dispatch `run` over a Unix domain socket instead of TCP, for agent runtimes exposed as a
+    /// local socket (e.g. a sidecar or containerized agent server) rather than a network port.
+    ///
+    /// `base_url`'s scheme is not checked once a socket path is set, and its host/port are
+    /// ignored in favor of `path`; the request line always targets `/`. `with_http_client`/TLS options are
+    /// meaningless once the connection goes over a socket file, so they're ignored when this is
+    /// set.
+    pub fn with_unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.unix_socket = Some(path.into());
+        self
+    }
+
+    /// Cap how much of a non-success response body is retained for
+    /// [`AgentError::HttpStatus`]/[`AgentError::ServerError`] (default 8 KiB).
+    ///
+    /// The body is truncated to this many characters before a structured JSON error payload is
+    /// parsed out of it, so a payload longer than the cap is not parsed and surfaces as
+    /// [`AgentError::HttpStatus`] with the truncated text.
+    pub fn with_max_error_body_bytes(mut self, max_error_body_bytes: usize) -> Self {
+        self.max_error_body_bytes = max_error_body_bytes;
+        self
+    }
+
+    /// Opt into automatic SSE reconnection: on a transport-level disconnect mid-stream (not a
+    /// protocol/deserialization error, unless `config.retry_on_stream_error` says otherwise) that
+    /// `self.retry_logic` classifies as retryable, re-issue the request with a `Last-Event-ID`
+    /// header set to the last event id seen, so a compliant server can resume the run instead of
+    /// losing it. Events the server replays at or before that id are de-duplicated before they
+    /// ever reach `decode_event`/the caller.
+    ///
+    /// `run_agent`'s event-dispatch loop never sees the disconnect -- it keeps consuming the same
+    /// `EventStream` and the same `EventHandler` -- since reconnection happens transparently
+    /// inside this stream. Each failed attempt is still surfaced separately, on a side channel, as
+    /// a [`crate::agent::TransportRetryEvent`] dispatched to
+    /// [`crate::subscriber::AgentSubscriber::on_transport_retry`] before the next attempt sleeps;
+    /// `on_run_failed` only follows if every retry in `config.max_retries` is exhausted. Has no
+    /// effect on a Unix-socket run, which has no `reqwest` transport errors to classify.
+    pub fn with_resumable_stream(mut self, config: ReconnectConfig) -> Self {
+        self.resume = Some(config);
+        self
+    }
+
+    /// Alias for [`Self::with_resumable_stream`], for callers reaching for the more familiar
+    /// "reconnect" name.
+    pub fn with_reconnect(self, config: ReconnectConfig) -> Self {
+        self.with_resumable_stream(config)
+    }
+
+    /// Throttle proactively instead of reacting to a provider's `429`s: every request dispatch
+    /// (the initial POST, its retries, and any SSE reconnect) waits for `limiter` to hand out a
+    /// token first, so the client never fires faster than `limiter`'s configured rate in the
+    /// first place.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
     pub fn build(self) -> Result<HttpAgent, AgentError> {
         let base_url = self.base_url.ok_or(AgentError::Config {
             message: "Base URL is required".to_string(),
         })?;
 
-        // Validate URL scheme
-        if !["http", "https"].contains(&base_url.scheme()) {
+        // Validate URL scheme: `unix` is accepted as an alternative way to request a
+        // `with_unix_socket`-style connection, using the URL's path as the socket path.
+        let unix_socket = match (self.unix_socket, base_url.scheme()) {
+            (Some(path), _) => Some(path),
+            (None, "unix") => Some(PathBuf::from(base_url.path())),
+            (None, _) => None,
+        };
+        if unix_socket.is_none() && !["http", "https"].contains(&base_url.scheme()) {
             return Err(AgentError::Config {
                 message: format!("Unsupported URL scheme: {}", base_url.scheme()),
             });
         }
 
-        let http_client = self.http_client.unwrap_or_default();
+        let has_custom_client_options = self.tls_root_certificate.is_some()
+            || self.client_identity.is_some()
+            || self.danger_accept_invalid_certs
+            || self.request_timeout.is_some()
+            || self.connect_timeout.is_some();
+
+        // TLS/timeout options configure a `reqwest::Client` that a Unix socket connection never
+        // touches; skip building one rather than erroring, since it's harmless to ignore.
+        let http_client = match (self.http_client, has_custom_client_options && unix_socket.is_none()) {
+            (Some(_), true) => {
+                return Err(AgentError::Config {
+                    message: "with_tls_root_certificate/with_client_identity/\
+                              with_danger_accept_invalid_certs/with_timeout/with_connect_timeout \
+                              cannot be combined with with_http_client; configure those on your \
+                              own client instead"
+                        .to_string(),
+                });
+            }
+            (Some(client), false) => client,
+            (None, false) => HttpClient::default(),
+            (None, true) => {
+                let mut builder = HttpClient::builder();
+                if let Some(cert) = self.tls_root_certificate {
+                    builder = builder.add_root_certificate(cert);
+                }
+                if let Some(identity) = self.client_identity {
+                    builder = builder.identity(identity);
+                }
+                if self.danger_accept_invalid_certs {
+                    builder = builder.danger_accept_invalid_certs(true);
+                }
+                if let Some(timeout) = self.request_timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                builder.build().map_err(|e| AgentError::Config {
+                    message: format!("failed to build TLS-configured HTTP client: {e}"),
+                })?
+            }
+        };
 
         Ok(HttpAgent {
             http_client,
             base_url,
             header_map: self.header_map,
             agent_id: self.agent_id,
+            encoding: self.encoding,
+            retry_policy: self.retry_policy,
+            retry_logic: self.retry_logic,
+            unix_socket,
+            max_error_body_bytes: self.max_error_body_bytes,
+            resume: self.resume,
+            rate_limiter: self.rate_limiter,
         })
     }
 }
@@ -163,37 +436,292 @@ where
         &self,
         input: &RunAgentInput<StateT, FwdPropsT>,
     ) -> Result<EventStream<'async_trait, StateT>, AgentError> {
-        // Send the request and get the response
-        let response = self
-            .http_client
-            .post(self.base_url.clone())
-            .json(input)
-            .headers(self.header_map.clone())
-            .send()
-            .await?;
-
-        // Check HTTP status and surface structured error on non-success
-        let status = response.status();
-        if !status.is_success() {
-            let text = response.text().await.unwrap_or_default();
-            let snippet: String = text.chars().take(512).collect();
-            return Err(AgentError::HttpStatus {
-                status,
-                context: snippet,
-            });
+        let body = self.encoding.encode(input).map_err(|e| AgentError::Config {
+            message: format!("failed to encode request body as {:?}: {e}", self.encoding),
+        })?;
+
+        if let Some(socket_path) = &self.unix_socket {
+            return self.run_over_unix_socket(socket_path, body).await;
+        }
+
+        let response = self.send_with_retries(body.clone()).await?;
+
+        // Select the decoding format from the response's actual Content-Type, falling back to
+        // the encoding we requested when the header is absent or unrecognized.
+        let response_encoding = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|v| v.to_str().ok())
+            .and_then(EncodingType::from_content_type)
+            .unwrap_or(self.encoding);
+
+        // Convert the response to an SSE event stream, transparently reconnecting on a resumable
+        // disconnect if the caller opted in via `with_resumable_stream`. No subscriber is around
+        // to receive `on_transport_retry` notices from a bare `run`, so no sender is wired up.
+        let stream = if let Some(resume) = self.resume.clone() {
+            self.resumable_event_stream(response, response_encoding, resume, body, None)
+                .await
+                .boxed()
+        } else {
+            response
+                .event_source()
+                .await
+                .map(move |result| match result {
+                    Ok(event) => {
+                        trace!("Received event: {event:?}");
+
+                        let event_data = decode_event::<StateT>(&event.data, response_encoding)?;
+                        debug!("Deserialized event: {event_data:?}");
+
+                        Ok(event_data)
+                    }
+                    Err(err) => Err(err),
+                })
+                .boxed()
+        };
+        Ok(stream)
+    }
+
+    /// Runs the agent with the same transport as the default `run_agent`, but also drains the
+    /// [`TransportRetryEvent`] notices a resumable stream (see `with_resumable_stream`) emits
+    /// mid-run, dispatching each to `on_transport_retry` as it happens -- something the default
+    /// `run_agent` (built purely around [`Agent::run`]'s one-way `EventStream`) has no channel
+    /// for.
+    async fn run_agent(
+        &self,
+        params: &RunAgentParams<StateT, FwdPropsT>,
+        subscribers: impl IntoSubscribers<StateT, FwdPropsT>,
+    ) -> Result<RunAgentResult<StateT>, AgentError> {
+        let input = RunAgentInput {
+            thread_id: ThreadId::random(),
+            run_id: params.run_id.clone().unwrap_or_else(RunId::random),
+            state: params.state.clone(),
+            messages: params.messages.clone(),
+            tools: params.tools.clone(),
+            context: params.context.clone(),
+            forwarded_props: params.forwarded_props.clone(),
+        };
+        let current_message_ids: HashSet<&MessageId> =
+            params.messages.iter().map(|m| m.id()).collect();
+
+        let subscribers = subscribers.into_subscribers();
+        let mut event_handler = EventHandler::new(
+            params.messages.clone(),
+            params.state.clone(),
+            &input,
+            subscribers.clone(),
+        );
+
+        let body = self.encoding.encode(&input).map_err(|e| AgentError::Config {
+            message: format!("failed to encode request body as {:?}: {e}", self.encoding),
+        })?;
+
+        // The retry channel only ever carries anything when `self.resume` is set and the run
+        // goes over HTTP; the Unix-socket path and the no-resume path just get a receiver whose
+        // sender is dropped immediately, so the merged loop below stays a single code path.
+        let (stream, retry_rx) = if let Some(socket_path) = &self.unix_socket {
+            let (_tx, rx) = mpsc::unbounded();
+            (self.run_over_unix_socket(socket_path, body).await?, rx)
+        } else {
+            let response = self.send_with_retries(body.clone()).await?;
+            let response_encoding = response
+                .headers()
+                .get("Content-Type")
+                .and_then(|v| v.to_str().ok())
+                .and_then(EncodingType::from_content_type)
+                .unwrap_or(self.encoding);
+
+            if let Some(resume) = self.resume.clone() {
+                let (tx, rx) = mpsc::unbounded();
+                let stream = self
+                    .resumable_event_stream(response, response_encoding, resume, body, Some(tx))
+                    .await
+                    .boxed();
+                (stream, rx)
+            } else {
+                let (_tx, rx) = mpsc::unbounded();
+                let stream = response
+                    .event_source()
+                    .await
+                    .map(move |result| match result {
+                        Ok(event) => {
+                            trace!("Received event: {event:?}");
+                            let event_data = decode_event::<StateT>(&event.data, response_encoding)?;
+                            debug!("Deserialized event: {event_data:?}");
+                            Ok(event_data)
+                        }
+                        Err(err) => Err(err),
+                    })
+                    .boxed();
+                (stream, rx)
+            }
+        };
+
+        let mut merged =
+            futures::stream::select(stream.map(HttpRunItem::Event), retry_rx.map(HttpRunItem::Retry));
+
+        while let Some(item) = merged.next().await {
+            match item {
+                HttpRunItem::Event(Ok(event)) => {
+                    let mutation = event_handler.handle_event(&event).await?;
+                    event_handler.apply_mutation(mutation).await?;
+                }
+                HttpRunItem::Event(Err(e)) => {
+                    event_handler.on_error(&e).await?;
+                    return Err(e);
+                }
+                HttpRunItem::Retry(retry) => {
+                    for subscriber in &subscribers {
+                        let sub_params = AgentSubscriberParams {
+                            messages: &event_handler.messages,
+                            state: &event_handler.state,
+                            input: event_handler.input,
+                        };
+                        subscriber.on_transport_retry(&retry, sub_params).await?;
+                    }
+                }
+            }
+        }
+
+        event_handler.on_finalize().await?;
+        let new_messages = event_handler
+            .messages
+            .iter()
+            .filter(|m| !current_message_ids.contains(&m.id()))
+            .cloned()
+            .collect();
+
+        Ok(RunAgentResult {
+            result: event_handler.result,
+            new_messages,
+            new_state: event_handler.state,
+        })
+    }
+
+    fn agent_id(&self) -> Option<&AgentId> {
+        self.agent_id.as_ref()
+    }
+}
+
+/// One item out of the merged event/retry-notice stream driving [`HttpAgent`]'s `run_agent`
+/// override; see [`Agent::run_agent`].
+enum HttpRunItem<StateT: AgentState> {
+    Event(Result<Event<StateT>, AgentError>),
+    Retry(TransportRetryEvent),
+}
+
+impl HttpAgent {
+    /// Sends the initial POST, retrying a retriable failure (per `self.retry_logic`, with backoff
+    /// from `self.retry_policy`) since no SSE event has been observed yet and re-sending the same
+    /// body is still safe. Shared by [`Agent::run`] and `run_agent`'s retry-channel-aware variant.
+    async fn send_with_retries(&self, body: Vec<u8>) -> Result<reqwest::Response, AgentError> {
+        let mut attempt = 0u32;
+        loop {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire().await;
+            }
+            let result = self
+                .http_client
+                .post(self.base_url.clone())
+                .headers(self.header_map.clone())
+                .header("Content-Type", self.encoding.content_type())
+                .header("Accept", self.encoding.content_type())
+                .body(body.clone())
+                .send()
+                .await;
+
+            let outcome = match result {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let retry_after = retry_after_duration(response.headers());
+                    let text = response.text().await.unwrap_or_default();
+                    let body: String = text.chars().take(self.max_error_body_bytes).collect();
+                    RetryOutcome::Status {
+                        status,
+                        body,
+                        retry_after,
+                    }
+                }
+                Err(e) => RetryOutcome::Transport(e),
+            };
+
+            let delay = match self.retry_logic.classify(&outcome) {
+                RetryAction::Fail(err) => return Err(err),
+                RetryAction::DontRetry => return Err(outcome.into_error()),
+                _ if attempt >= self.retry_policy.max_retries => {
+                    return Err(outcome.into_error());
+                }
+                RetryAction::Retry => jittered_delay(self.retry_policy.backoff_for(attempt)),
+                RetryAction::RetryAfter(delay) => delay,
+            };
+
+            debug!(
+                "run attempt {} failed ({:?}), retrying in {delay:?}",
+                attempt + 1,
+                outcome
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         }
+    }
+
+    /// The Unix-socket counterpart of the TCP path in [`Agent::run`]: send the same request,
+    /// but over a UDS connection (see [`crate::uds`]) rather than through `self.http_client`.
+    ///
+    /// `self.retry_policy`/`self.retry_logic` aren't applied here -- they're built around
+    /// `reqwest`'s status/transport-error types, which a hand-rolled socket connection doesn't
+    /// produce. A failed connection or non-success status simply fails the run.
+    async fn run_over_unix_socket<StateT>(
+        &self,
+        socket_path: &Path,
+        body: Vec<u8>,
+    ) -> Result<EventStream<'static, StateT>, AgentError>
+    where
+        StateT: AgentState,
+    {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+
+        let mut headers: Vec<(String, String)> = self
+            .header_map
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        headers.push(("Content-Type".to_string(), self.encoding.content_type().to_string()));
+        headers.push(("Accept".to_string(), self.encoding.content_type().to_string()));
+
+        let (head, body_stream) =
+            uds::send_request(socket_path, "/", "localhost", &headers, body).await?;
 
-        // Convert the response to an SSE event stream
-        let stream = response
-            .event_source()
-            .await
-            .map(|result| match result {
+        if !head.status.is_success() {
+            let mut context = String::new();
+            let mut body_stream = Box::pin(body_stream);
+            while context.len() < self.max_error_body_bytes {
+                match body_stream.next().await {
+                    Some(Ok(chunk)) => context.push_str(&String::from_utf8_lossy(&chunk)),
+                    _ => break,
+                }
+            }
+            let context: String = context.chars().take(self.max_error_body_bytes).collect();
+            let retry_after = head
+                .header("retry-after")
+                .and_then(|v| parse_retry_after(v, std::time::SystemTime::now()));
+            return Err(AgentError::from_response_body(head.status, context, retry_after));
+        }
+
+        let response_encoding = head
+            .header("content-type")
+            .and_then(EncodingType::from_content_type)
+            .unwrap_or(self.encoding);
+
+        let stream = sse_event_stream(body_stream)
+            .map(move |result| match result {
                 Ok(event) => {
                     trace!("Received event: {event:?}");
-
-                    let event_data: Event<StateT> = serde_json::from_str(&event.data)?;
+                    let event_data = decode_event::<StateT>(&event.data, response_encoding)?;
                     debug!("Deserialized event: {event_data:?}");
-
                     Ok(event_data)
                 }
                 Err(err) => Err(err),
@@ -202,7 +730,386 @@ where
         Ok(stream)
     }
 
-    fn agent_id(&self) -> Option<&AgentId> {
-        self.agent_id.as_ref()
+    /// Wraps `initial_response`'s SSE stream with transparent `Last-Event-ID` reconnection.
+    ///
+    /// A transport-level disconnect (`AgentError::HttpTransport`) that `self.retry_logic`
+    /// classifies as retryable triggers a reconnect; a protocol/deserialization error (e.g.
+    /// `SseParse`) normally ends the stream instead, since re-sending the same request wouldn't
+    /// fix a malformed payload. The exception is `config.retry_on_stream_error`: when set, a
+    /// malformed frame or an `Event::RunError` payload reconnects the same way a disconnect would,
+    /// but only while no event has reached the caller yet for this run -- analogous to an object
+    /// store retrying a "200 with Error in body" response, and unsafe once the run is no longer
+    /// idempotent. Events are de-duplicated by their SSE `id:` field (when the server sets one)
+    /// before being decoded, so a server that conservatively replays a few events around the
+    /// resume point doesn't hand `run_agent` the same event twice.
+    ///
+    /// `retry_notify`, when set, receives a [`TransportRetryEvent`] for every failed reconnect
+    /// attempt right before it sleeps out the backoff, so a caller with subscribers (see
+    /// `HttpAgent::run_agent`) can dispatch `on_transport_retry` without this stream knowing
+    /// anything about subscribers itself.
+    async fn resumable_event_stream<StateT: AgentState>(
+        &self,
+        initial_response: reqwest::Response,
+        response_encoding: EncodingType,
+        config: ReconnectConfig,
+        body: Vec<u8>,
+        retry_notify: Option<mpsc::UnboundedSender<TransportRetryEvent>>,
+    ) -> impl Stream<Item = Result<Event<StateT>, AgentError>> + use<StateT> {
+        let state = ResumableStreamState {
+            http_client: self.http_client.clone(),
+            base_url: self.base_url.clone(),
+            header_map: self.header_map.clone(),
+            content_type: self.encoding.content_type(),
+            body,
+            retry_logic: self.retry_logic.clone(),
+            config,
+            last_event_id: None,
+            seen_ids: HashSet::new(),
+            retries_used: 0,
+            any_event_delivered: false,
+            retry_notify,
+            rate_limiter: self.rate_limiter.clone(),
+            inner: Some(initial_response.event_source().await),
+        };
+
+        futures::stream::unfold(state, move |mut state| async move {
+            loop {
+                if state.inner.is_none() {
+                    if let Some(limiter) = &state.rate_limiter {
+                        limiter.acquire().await;
+                    }
+                    match state.reconnect_request().send().await {
+                        Ok(response) => state.inner = Some(response.event_source().await),
+                        Err(e) => match state.next_after_transport_error(e).await {
+                            Some(err) => return Some((Err(err), state)),
+                            None => continue,
+                        },
+                    }
+                }
+
+                match state.inner.as_mut().unwrap().next().await {
+                    Some(Ok(event)) => {
+                        state.retries_used = 0;
+                        if let Some(id) = &event.id {
+                            if !state.seen_ids.insert(id.clone()) {
+                                continue;
+                            }
+                            state.last_event_id = Some(id.clone());
+                        }
+                        let decoded = decode_event::<StateT>(&event.data, response_encoding);
+                        let in_band_error = matches!(
+                            &decoded,
+                            Err(AgentError::SseParse { .. }) | Err(AgentError::Json(_))
+                        ) || matches!(&decoded, Ok(Event::RunError(_)));
+                        if in_band_error
+                            && state.config.retry_on_stream_error
+                            && !state.any_event_delivered
+                        {
+                            state.inner = None;
+                            let terminal = match decoded {
+                                Ok(Event::RunError(e)) => AgentError::Execution {
+                                    message: match e.code {
+                                        Some(code) => format!("agent run error ({code}): {}", e.message),
+                                        None => format!("agent run error: {}", e.message),
+                                    },
+                                },
+                                Err(err) => err,
+                                Ok(_) => unreachable!("in_band_error implies Err or RunError"),
+                            };
+                            match state.next_after_stream_error(terminal).await {
+                                Some(err) => return Some((Err(err), state)),
+                                None => continue,
+                            }
+                        }
+                        if decoded.is_ok() {
+                            state.any_event_delivered = true;
+                        }
+                        return Some((decoded, state));
+                    }
+                    Some(Err(AgentError::HttpTransport(e))) => {
+                        state.inner = None;
+                        match state.next_after_transport_error(e).await {
+                            Some(err) => return Some((Err(err), state)),
+                            None => continue,
+                        }
+                    }
+                    Some(Err(err)) => return Some((Err(err), state)),
+                    None => return None,
+                }
+            }
+        })
+    }
+}
+
+/// State threaded through [`HttpAgent::resumable_event_stream`]'s `futures::stream::unfold`: an
+/// owned clone of everything needed to re-issue the request, since the stream must outlive `&self`.
+struct ResumableStreamState {
+    http_client: HttpClient,
+    base_url: Url,
+    header_map: HeaderMap,
+    content_type: &'static str,
+    body: Vec<u8>,
+    retry_logic: Arc<dyn RetryLogic>,
+    config: ReconnectConfig,
+    /// The most recent SSE event `id` seen, sent back as `Last-Event-ID` on reconnect.
+    last_event_id: Option<String>,
+    /// Every id seen so far this run, to drop events the server replays on resume.
+    ///
+    /// Grows for the lifetime of one run rather than being pruned -- a single run's event count
+    /// is bounded in practice, and the whole point of resumption is not losing any of it.
+    seen_ids: HashSet<String>,
+    retries_used: u32,
+    /// Whether any event has been handed back to the caller yet this run -- once one has, an
+    /// in-band stream error (see [`ReconnectConfig::retry_on_stream_error`]) is no longer safe to
+    /// retry, since the caller may already have committed it.
+    any_event_delivered: bool,
+    /// Fed a [`TransportRetryEvent`] before each retry attempt sleeps, when the caller (see
+    /// `HttpAgent::run_agent`) wants to surface reconnects to subscribers.
+    retry_notify: Option<mpsc::UnboundedSender<TransportRetryEvent>>,
+    /// Awaited before every reconnect request, mirroring [`HttpAgent::send_with_retries`]'s own
+    /// gate on the initial POST; see [`HttpAgentBuilder::with_rate_limiter`].
+    rate_limiter: Option<RateLimiter>,
+    inner: Option<Pin<Box<dyn Stream<Item = Result<SseEvent, AgentError>> + Send>>>,
+}
+
+impl ResumableStreamState {
+    fn reconnect_request(&self) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .http_client
+            .post(self.base_url.clone())
+            .headers(self.header_map.clone())
+            .header("Content-Type", self.content_type)
+            .header("Accept", self.content_type)
+            .body(self.body.clone());
+        if let Some(id) = &self.last_event_id {
+            builder = builder.header("Last-Event-ID", id.clone());
+        }
+        builder
+    }
+
+    /// Classifies a mid-stream transport error via `self.retry_logic`: `None` means the caller
+    /// already waited out the backoff and should loop back to reconnect; `Some(err)` means give
+    /// up and surface `err` as the stream's final item.
+    async fn next_after_transport_error(&mut self, error: reqwest::Error) -> Option<AgentError> {
+        let message = error.to_string();
+        let outcome = RetryOutcome::Transport(error);
+        let delay = match self.retry_logic.classify(&outcome) {
+            RetryAction::Fail(err) => return Some(err),
+            RetryAction::DontRetry => return Some(outcome.into_error()),
+            _ if self.retries_used >= self.config.max_retries => {
+                return Some(outcome.into_error());
+            }
+            RetryAction::Retry => {
+                jittered_delay(reconnect_backoff(&self.config, self.retries_used))
+            }
+            RetryAction::RetryAfter(delay) => delay,
+        };
+
+        debug!(
+            "resumable stream reconnect attempt {} failed, retrying in {delay:?}",
+            self.retries_used + 1
+        );
+        if let Some(notify) = &self.retry_notify {
+            let _ = notify.unbounded_send(TransportRetryEvent {
+                attempt: self.retries_used + 1,
+                delay,
+                message,
+            });
+        }
+        tokio::time::sleep(delay).await;
+        self.retries_used += 1;
+        None
+    }
+
+    /// Classifies an in-band stream error (see [`ReconnectConfig::retry_on_stream_error`]) the
+    /// same way [`Self::next_after_transport_error`] classifies a dropped connection: `None`
+    /// means the caller already waited out the backoff and should loop back to reconnect, `Some`
+    /// means give up and surface `terminal` as the stream's final item.
+    async fn next_after_stream_error(&mut self, terminal: AgentError) -> Option<AgentError> {
+        if self.retries_used >= self.config.max_retries {
+            return Some(terminal);
+        }
+
+        let delay = jittered_delay(reconnect_backoff(&self.config, self.retries_used));
+        debug!(
+            "resumable stream saw an in-band error before any event was delivered, retrying in {delay:?}: {terminal}"
+        );
+        if let Some(notify) = &self.retry_notify {
+            let _ = notify.unbounded_send(TransportRetryEvent {
+                attempt: self.retries_used + 1,
+                delay,
+                message: terminal.to_string(),
+            });
+        }
+        tokio::time::sleep(delay).await;
+        self.retries_used += 1;
+        None
+    }
+}
+
+/// Exponential backoff for resumable-stream reconnects, mirroring [`RetryPolicy::backoff_for`]
+/// but driven by [`ReconnectConfig`]'s `default_delay`/`max_delay` fields.
+fn reconnect_backoff(config: &ReconnectConfig, attempt: u32) -> Duration {
+    let exp = config.default_delay.saturating_mul(1u32 << attempt.min(16));
+    exp.min(config.max_delay)
+}
+
+/// Reads the `Retry-After` response header, if present, and parses it via [`parse_retry_after`]
+/// (delta-seconds or HTTP-date; see that function's doc comment for the full grammar).
+fn retry_after_duration(headers: &HeaderMap) -> Option<Duration> {
+    let header = headers.get("Retry-After")?.to_str().ok()?;
+    parse_retry_after(header, std::time::SystemTime::now())
+}
+
+/// Parses a `Retry-After` header value in either form RFC 9110 allows: delta-seconds (`"120"`)
+/// or an HTTP-date (`"Sun, 06 Nov 1994 08:49:37 GMT"`), returning the remaining delay from `now`.
+/// A date already in the past yields `None` rather than a negative duration.
+fn parse_retry_after(value: &str, now: std::time::SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    parse_http_date_delay(value, now)
+}
+
+/// Parses an RFC 7231 IMF-fixdate (e.g. `"Sun, 06 Nov 1994 08:49:37 GMT"`) -- the only form
+/// `Retry-After` and other HTTP-date headers are required to send -- into the remaining
+/// [`Duration`] from `now` until that instant.
+fn parse_http_date_delay(value: &str, now: std::time::SystemTime) -> Option<Duration> {
+    let (_weekday, rest) = value.split_once(", ")?;
+    let mut parts = rest.split_whitespace();
+    let day: i64 = parts.next()?.parse().ok()?;
+    let month = month_number(parts.next()?)?;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut time_parts = parts.next()?.split(':');
+    let hour: i64 = time_parts.next()?.parse().ok()?;
+    let minute: i64 = time_parts.next()?.parse().ok()?;
+    let second: i64 = time_parts.next()?.parse().ok()?;
+    if parts.next()? != "GMT" {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let epoch_seconds = days * 86_400 + hour * 3600 + minute * 60 + second;
+    let target = std::time::SystemTime::UNIX_EPOCH
+        .checked_add(Duration::from_secs(u64::try_from(epoch_seconds).ok()?))?;
+    target.duration_since(now).ok()
+}
+
+fn month_number(name: &str) -> Option<i64> {
+    Some(match name {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    })
+}
+
+/// Days since the Unix epoch for a proleptic-Gregorian calendar date, via Howard Hinnant's
+/// `days_from_civil` algorithm -- used here instead of pulling in a date/time crate for the one
+/// job of turning a parsed `Retry-After` date into an instant.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let mp = (m + 9) % 12; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + d - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+/// Applies full jitter to a computed backoff: a uniformly random duration in `[0, computed]`.
+///
+/// Seeded from the current time rather than pulling in a dependency dedicated to this one call
+/// site -- the randomness only needs to desynchronize retrying clients, not resist prediction.
+/// `pub(crate)` so [`crate::retry::ExponentialBackoff`] can share it instead of reimplementing
+/// the same xorshift.
+pub(crate) fn jittered_delay(computed: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0x9E3779B97F4A7C15);
+    let mut x = (nanos ^ (computed.as_nanos() as u64)) | 1;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    let fraction = (x % 1_000_001) as f64 / 1_000_000.0;
+    computed.mul_f64(fraction)
+}
+
+/// Decode a single SSE `data:` payload into an [`Event`], honoring the negotiated encoding.
+///
+/// JSON payloads are valid UTF-8 text and are decoded directly. Binary encodings (MessagePack,
+/// bincode) cannot travel as raw SSE text, so the server base64-wraps the payload; unwrap that
+/// before handing the bytes to the encoder.
+fn decode_event<StateT: AgentState>(
+    data: &str,
+    encoding: EncodingType,
+) -> Result<Event<StateT>, AgentError> {
+    if matches!(encoding, EncodingType::Json) {
+        return Ok(serde_json::from_str(data)?);
+    }
+
+    let bytes = base64_decode(data.as_bytes()).ok_or_else(|| AgentError::SseParse {
+        message: format!("expected base64-wrapped {encoding:?} payload, got: {data}"),
+    })?;
+    encoding
+        .decode(&bytes)
+        .map_err(|e| AgentError::SseParse {
+            message: format!("failed to decode {encoding:?} event: {e}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_retry_after_reads_delta_seconds() {
+        let now = std::time::SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("  45  ", now),
+            Some(Duration::from_secs(45))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_reads_an_http_date_in_the_future() {
+        // 1994-11-06T08:49:37Z is 784111777 seconds after the epoch.
+        let now = std::time::SystemTime::UNIX_EPOCH + Duration::from_secs(784_111_777 - 60);
+        let delay = parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", now).unwrap();
+        assert_eq!(delay, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_an_http_date_already_in_the_past() {
+        let now = std::time::SystemTime::UNIX_EPOCH + Duration::from_secs(784_111_777 + 60);
+        assert_eq!(parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", now), None);
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_garbage() {
+        let now = std::time::SystemTime::now();
+        assert_eq!(parse_retry_after("not a valid header", now), None);
+    }
+
+    #[test]
+    fn days_from_civil_matches_known_epoch_offsets() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
     }
 }
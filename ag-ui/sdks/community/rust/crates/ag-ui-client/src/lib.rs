@@ -1,13 +1,39 @@
 #![doc = include_str!("../README.md")]
 
 pub mod agent;
+pub mod agui_event;
+pub mod channel_subscriber;
+pub mod envelope;
 pub mod error;
 pub mod event_handler;
 pub mod http;
+pub mod message_accumulator;
+pub mod mock;
+pub mod rate_limiter;
+pub mod reducer;
+pub mod retry;
+pub mod server;
 pub mod sse;
+pub mod sse_codec;
 pub(crate) mod stream;
 pub mod subscriber;
+pub mod tool_loop;
+pub mod uds;
+pub mod wire;
+pub mod ws;
 pub use agent::{Agent, RunAgentParams};
+pub use agui_event::AgUiEvent;
+pub use channel_subscriber::{BroadcastSubscriber, ChannelSubscriber, FanOutItem, FanOutSubscriber};
+pub use envelope::{Envelope, EnvelopeError, EnvelopeHeader};
 pub use http::HttpAgent;
+pub use message_accumulator::MessageAccumulator;
+pub use mock::MockAgent;
+pub use rate_limiter::RateLimiter;
+pub use reducer::{EventReducer, ReduceError};
+pub use retry::{DefaultRetryPolicy, ExponentialBackoff, RetryPolicy};
+pub use server::LiveRunServer;
+pub use tool_loop::{ToolHandlerError, ToolLoop, ToolLoopBuilder, ToolLoopError};
+pub use wire::{EventReader, EventWriter, TransportError, WireFormat};
+pub use ws::WsAgent;
 
 pub use ag_ui_core as core;
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use futures::StreamExt;
+
+use crate::agent::AgentError;
+use crate::core::AgentState;
+use crate::core::event::Event;
+use crate::core::types::{AssistantMessage, FunctionCall, MessageId, ToolCall, ToolCallId};
+use crate::stream::EventStream;
+
+/// Incrementally builds a single [`AssistantMessage`] out of the deltas an [`EventStream`] streams
+/// for one assistant turn.
+///
+/// `TextMessageContent`/`TextMessageChunk` deltas are appended to the message's content, and
+/// `ToolCallStart`/`ToolCallArgs`/`ToolCallChunk` deltas are merged into `tool_calls`, keyed by
+/// `toolCallId` -- providers routinely split a single JSON arguments object across many chunks, so
+/// each tool call's fragments are concatenated in arrival order rather than overwritten. This
+/// complements [`crate::reducer::EventReducer`], which folds a whole multi-message conversation;
+/// `MessageAccumulator` is for the narrower, common case of rendering one assistant reply as it
+/// streams in (a chat UI's "typing" bubble).
+#[derive(Debug, Clone, Default)]
+pub struct MessageAccumulator {
+    message_id: Option<MessageId>,
+    content: String,
+    tool_calls: Vec<ToolCall>,
+    tool_call_index: HashMap<ToolCallId, usize>,
+}
+
+impl MessageAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds one streamed event into the message being assembled. Events unrelated to an
+    /// assistant message (state, run lifecycle, tool results, ...) are ignored.
+    pub fn push<StateT: AgentState>(&mut self, event: &Event<StateT>) {
+        match event {
+            Event::TextMessageStart(e) => {
+                self.message_id.get_or_insert_with(|| e.message_id.clone());
+            }
+            Event::TextMessageContent(e) => {
+                self.content.push_str(&e.delta);
+            }
+            Event::TextMessageChunk(e) => {
+                if let Some(message_id) = &e.message_id {
+                    self.message_id.get_or_insert_with(|| message_id.clone());
+                }
+                if let Some(delta) = &e.delta {
+                    self.content.push_str(delta);
+                }
+            }
+            Event::ToolCallStart(e) => {
+                let index = self.tool_call_index(e.tool_call_id.clone());
+                self.tool_calls[index].function.name = e.tool_call_name.clone();
+            }
+            Event::ToolCallArgs(e) => {
+                let index = self.tool_call_index(e.tool_call_id.clone());
+                self.tool_calls[index].function.arguments.push_str(&e.delta);
+            }
+            Event::ToolCallChunk(e) => {
+                if let Some(tool_call_id) = e.tool_call_id.clone() {
+                    let index = self.tool_call_index(tool_call_id);
+                    if let Some(name) = &e.tool_call_name {
+                        self.tool_calls[index].function.name = name.clone();
+                    }
+                    if let Some(delta) = &e.delta {
+                        self.tool_calls[index].function.arguments.push_str(delta);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the index into `tool_calls` for `tool_call_id`, creating a blank entry in arrival
+    /// order the first time a delta for it is seen.
+    fn tool_call_index(&mut self, tool_call_id: ToolCallId) -> usize {
+        if let Some(&index) = self.tool_call_index.get(&tool_call_id) {
+            return index;
+        }
+
+        self.tool_calls.push(ToolCall::new(
+            tool_call_id.clone(),
+            FunctionCall {
+                name: String::new(),
+                arguments: String::new(),
+            },
+        ));
+        let index = self.tool_calls.len() - 1;
+        self.tool_call_index.insert(tool_call_id, index);
+        index
+    }
+
+    /// Finishes accumulation, producing the assembled [`AssistantMessage`]. Falls back to a random
+    /// [`MessageId`] if no `TextMessageStart`/`TextMessageChunk` ever supplied one (e.g. a
+    /// tool-call-only turn).
+    pub fn finish(self) -> AssistantMessage {
+        let mut message = AssistantMessage::new(self.message_id.unwrap_or_else(MessageId::random));
+        if !self.content.is_empty() {
+            message = message.with_content(self.content);
+        }
+        if !self.tool_calls.is_empty() {
+            message = message.with_tool_calls(self.tool_calls);
+        }
+        message
+    }
+}
+
+/// Drives `stream` to completion, folding every event through a [`MessageAccumulator`] and
+/// returning the assembled [`AssistantMessage`].
+pub async fn collect<StateT: AgentState>(
+    mut stream: EventStream<'_, StateT>,
+) -> Result<AssistantMessage, AgentError> {
+    let mut accumulator = MessageAccumulator::new();
+    while let Some(event) = stream.next().await {
+        accumulator.push(&event?);
+    }
+    Ok(accumulator.finish())
+}
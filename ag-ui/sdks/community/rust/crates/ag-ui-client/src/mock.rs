@@ -0,0 +1,190 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc;
+use futures::StreamExt;
+
+use crate::agent::{Agent, AgentError, AgentStateMutation};
+use crate::core::event::{Event, EventType};
+use crate::core::types::{AgentId, RunAgentInput};
+use crate::core::{AgentState, FwdProps, JsonValue};
+use crate::stream::EventStream;
+use crate::subscriber::{AgentSubscriber, AgentSubscriberParams};
+
+/// Filters which events a [`MockAgent`] run forwards into the subscriber pipeline.
+///
+/// Defaults to forwarding every scripted event. Use [`Query::only`] to scope a test to, say,
+/// only `ToolCall*` events without having to special-case them in the subscriber itself.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    kinds: Option<HashSet<EventType>>,
+}
+
+impl Query {
+    /// Only forward events whose [`EventType`] is in `kinds`.
+    pub fn only(kinds: impl IntoIterator<Item = EventType>) -> Self {
+        Self {
+            kinds: Some(kinds.into_iter().collect()),
+        }
+    }
+
+    fn matches<StateT: AgentState>(&self, event: &Event<StateT>) -> bool {
+        match &self.kinds {
+            None => true,
+            Some(kinds) => kinds.contains(&event.event_type()),
+        }
+    }
+}
+
+/// An in-process [`Agent`] that replays scripted event sequences instead of talking to a real
+/// agent server, so tests that shape themselves like `test_http_agent_tool_calls` can run
+/// deterministically offline.
+///
+/// Each call to [`Agent::run`] pops the next script queued via [`MockAgentBuilder::script`] and
+/// feeds it through an unbounded channel into the same [`EventStream`]/[`crate::event_handler::EventHandler`]
+/// pipeline [`crate::HttpAgent`] and [`crate::WsAgent`] use, so `on_*_event` callbacks on any
+/// attached [`AgentSubscriber`] fire exactly as they would against a live run.
+pub struct MockAgent<StateT: AgentState = JsonValue> {
+    agent_id: Option<AgentId>,
+    query: Query,
+    scripts: Mutex<VecDeque<Vec<Event<StateT>>>>,
+}
+
+impl<StateT: AgentState> MockAgent<StateT> {
+    pub fn builder() -> MockAgentBuilder<StateT> {
+        MockAgentBuilder::new()
+    }
+}
+
+pub struct MockAgentBuilder<StateT: AgentState = JsonValue> {
+    agent_id: Option<AgentId>,
+    query: Query,
+    scripts: VecDeque<Vec<Event<StateT>>>,
+}
+
+impl<StateT: AgentState> MockAgentBuilder<StateT> {
+    pub fn new() -> Self {
+        Self {
+            agent_id: None,
+            query: Query::default(),
+            scripts: VecDeque::new(),
+        }
+    }
+
+    pub fn with_agent_id(mut self, agent_id: AgentId) -> Self {
+        self.agent_id = Some(agent_id);
+        self
+    }
+
+    /// Only forward events matching `query` into the subscriber pipeline; see [`Query`].
+    pub fn with_query(mut self, query: Query) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Enqueue a scripted sequence of events, e.g. `RunStarted -> TextMessageStart ->
+    /// TextMessageContent -> TextMessageEnd -> RunFinished`, to be replayed on the next call to
+    /// `run()`. Calling this multiple times queues multiple scripted runs, popped in order.
+    pub fn script(mut self, events: Vec<Event<StateT>>) -> Self {
+        self.scripts.push_back(events);
+        self
+    }
+
+    pub fn build(self) -> MockAgent<StateT> {
+        MockAgent {
+            agent_id: self.agent_id,
+            query: self.query,
+            scripts: Mutex::new(self.scripts),
+        }
+    }
+}
+
+impl<StateT: AgentState> Default for MockAgentBuilder<StateT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateT, FwdPropsT> Agent<StateT, FwdPropsT> for MockAgent<StateT>
+where
+    StateT: AgentState,
+    FwdPropsT: FwdProps,
+{
+    async fn run(
+        &self,
+        _input: &RunAgentInput<StateT, FwdPropsT>,
+    ) -> Result<EventStream<'async_trait, StateT>, AgentError> {
+        let events = self
+            .scripts
+            .lock()
+            .expect("MockAgent scripts mutex poisoned")
+            .pop_front()
+            .ok_or_else(|| AgentError::Config {
+                message: "MockAgent has no more scripted event sequences queued".to_string(),
+            })?;
+
+        let query = self.query.clone();
+        let (tx, rx) = mpsc::unbounded();
+        tokio::spawn(async move {
+            for event in events {
+                if !query.matches(&event) {
+                    continue;
+                }
+                if tx.unbounded_send(Ok(event)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(rx.boxed())
+    }
+
+    fn agent_id(&self) -> Option<&AgentId> {
+        self.agent_id.as_ref()
+    }
+}
+
+/// An [`AgentSubscriber`] that records every event it observes, so a test can assert on what a
+/// [`MockAgent`] run actually emitted without wiring up bespoke bookkeeping each time.
+///
+/// `run_agent` takes its subscribers by value, so `events()` must be read through a cheap
+/// [`Clone`] of the subscriber taken before it's handed off, rather than through the instance
+/// passed into the run.
+#[derive(Default, Clone)]
+pub struct RecordingSubscriber<StateT: AgentState = JsonValue> {
+    events: Arc<Mutex<Vec<Event<StateT>>>>,
+}
+
+impl<StateT: AgentState> RecordingSubscriber<StateT> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A clone of every event observed so far, in emission order.
+    pub fn events(&self) -> Vec<Event<StateT>> {
+        self.events
+            .lock()
+            .expect("RecordingSubscriber events mutex poisoned")
+            .clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateT, FwdPropsT> AgentSubscriber<StateT, FwdPropsT> for RecordingSubscriber<StateT>
+where
+    StateT: AgentState,
+    FwdPropsT: FwdProps,
+{
+    async fn on_event(
+        &self,
+        event: &Event<StateT>,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.events
+            .lock()
+            .expect("RecordingSubscriber events mutex poisoned")
+            .push(event.clone());
+        Ok(AgentStateMutation::default())
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use crate::error::AgUiClientError;
+
+/// A token-bucket rate limiter a caller awaits before dispatching each HTTP/SSE request, to stay
+/// under a provider's requests-per-period (or compute-units-per-second) limit proactively rather
+/// than reacting to a `429` after the fact -- see [`crate::http::HttpAgentBuilder::with_rate_limiter`].
+///
+/// The bucket starts full (`max_per_period` tokens) and refills continuously at
+/// `max_per_period / period`, so bursts up to the full capacity are allowed but sustained
+/// throughput is capped at the configured rate. Cheap to clone: the bucket itself lives behind an
+/// `Arc`, so every clone shares the same limit.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Builds a bucket that allows up to `max_per_period` requests per `period`, refilling
+    /// continuously rather than all at once at period boundaries.
+    ///
+    /// Returns [`AgUiClientError::Config`] if `max_per_period` is zero or `period` is zero, since
+    /// neither describes a meaningful rate.
+    pub fn new(max_per_period: u32, period: Duration) -> Result<Self, AgUiClientError> {
+        if max_per_period == 0 {
+            return Err(AgUiClientError::config(
+                "RateLimiter max_per_period must be greater than zero",
+            ));
+        }
+        if period.is_zero() {
+            return Err(AgUiClientError::config(
+                "RateLimiter period must be greater than zero",
+            ));
+        }
+
+        let capacity = max_per_period as f64;
+        Ok(Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            })),
+            capacity,
+            refill_per_sec: capacity / period.as_secs_f64(),
+        })
+    }
+
+    /// Waits until a token is available, then consumes it. Dispatch the request immediately
+    /// after this returns -- the wait is the throttle, not a guarantee the token stays reserved.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                bucket.last_refill = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - bucket.tokens;
+                    Some(Duration::from_secs_f64(deficit / self.refill_per_sec))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(delay) => tokio::time::sleep(delay).await,
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use crate::core::event::Event;
+use crate::core::types::{
+    FunctionCall, Message, MessageContent, MessageId, Role, ToolCall, ToolCallId,
+};
+use crate::core::{AgentState, StateDeltaError, apply_state_delta};
+
+/// Errors produced while folding a live [`Event`] stream into messages and state.
+#[derive(Debug, thiserror::Error)]
+pub enum ReduceError {
+    /// A content/end event referenced a `messageId` that was never opened by a `*Start` event.
+    #[error("event references unknown message id {0}")]
+    UnknownMessage(MessageId),
+
+    /// An args/end event referenced a `toolCallId` that was never opened by a `*Start` event.
+    #[error("event references unknown tool call id {0}")]
+    UnknownToolCall(ToolCallId),
+
+    /// A `TextMessageChunk`/`ToolCallChunk` with no id arrived while nothing was open to
+    /// continue.
+    #[error("chunk event with no id arrived but no message/tool call is currently open")]
+    NoOpenChunkTarget,
+
+    /// Applying a `StateDelta` event's JSON Patch failed.
+    #[error("failed to apply state delta: {0}")]
+    StateDelta(#[from] StateDeltaError),
+}
+
+/// A text or thinking message being assembled from `*Start`/`*Content`/`*End` events.
+#[derive(Debug, Clone)]
+struct PartialMessage {
+    role: Role,
+    content: String,
+    tool_calls: Vec<ToolCall>,
+}
+
+/// A tool call being assembled from `ToolCallStart`/`ToolCallArgs`/`ToolCallEnd` events.
+#[derive(Debug, Clone)]
+struct PartialToolCall {
+    name: String,
+    arguments: String,
+    parent_message_id: Option<MessageId>,
+}
+
+/// Folds an ordered stream of [`Event`] values into the running conversation: the finished
+/// [`Message`]s and the current `StateT`.
+///
+/// This mirrors the "default behavior" [`crate::event_handler::EventHandler`] applies during a
+/// `run_agent` call, but as a standalone, dependency-free reducer callers can drive themselves
+/// against a raw event stream (e.g. one read off [`crate::sse::SseResponseExt`] or a custom
+/// transport) without going through the `Agent`/`AgentSubscriber` machinery.
+#[derive(Debug, Clone)]
+pub struct EventReducer<StateT: AgentState> {
+    messages: Vec<Message>,
+    state: StateT,
+    open_messages: HashMap<MessageId, PartialMessage>,
+    open_tool_calls: HashMap<ToolCallId, PartialToolCall>,
+    /// Ids in `open_messages`, in the order they were opened, so a no-id `TextMessageChunk` can
+    /// continue the most recently opened one -- `HashMap` iteration order is not insertion order.
+    open_message_order: Vec<MessageId>,
+    /// Ids in `open_tool_calls`, in the order they were opened; see `open_message_order`.
+    open_tool_call_order: Vec<ToolCallId>,
+}
+
+impl<StateT: AgentState> EventReducer<StateT> {
+    /// Creates a reducer starting from empty messages and the default state.
+    pub fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+            state: StateT::default(),
+            open_messages: HashMap::new(),
+            open_tool_calls: HashMap::new(),
+            open_message_order: Vec::new(),
+            open_tool_call_order: Vec::new(),
+        }
+    }
+
+    /// Creates a reducer starting from the given messages and state, e.g. to resume after a
+    /// `MessagesSnapshot`/`StateSnapshot` pair.
+    pub fn with_initial(messages: Vec<Message>, state: StateT) -> Self {
+        Self {
+            messages,
+            state,
+            open_messages: HashMap::new(),
+            open_tool_calls: HashMap::new(),
+            open_message_order: Vec::new(),
+            open_tool_call_order: Vec::new(),
+        }
+    }
+
+    /// The finished messages accumulated so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// The current state.
+    pub fn state(&self) -> &StateT {
+        &self.state
+    }
+
+    /// Folds a single event into the running messages/state.
+    pub fn apply(&mut self, event: &Event<StateT>) -> Result<(), ReduceError> {
+        match event {
+            Event::TextMessageStart(e) => {
+                self.open_messages.insert(
+                    e.message_id.clone(),
+                    PartialMessage {
+                        role: e.role.clone(),
+                        content: String::new(),
+                        tool_calls: Vec::new(),
+                    },
+                );
+                self.open_message_order.push(e.message_id.clone());
+            }
+            Event::TextMessageContent(e) => {
+                let partial = self
+                    .open_messages
+                    .get_mut(&e.message_id)
+                    .ok_or_else(|| ReduceError::UnknownMessage(e.message_id.clone()))?;
+                partial.content.push_str(&e.delta);
+            }
+            Event::TextMessageEnd(e) => {
+                let partial = self
+                    .open_messages
+                    .remove(&e.message_id)
+                    .ok_or_else(|| ReduceError::UnknownMessage(e.message_id.clone()))?;
+                self.open_message_order.retain(|id| id != &e.message_id);
+                self.messages
+                    .push(finish_message(e.message_id.clone(), partial));
+            }
+            Event::TextMessageChunk(e) => {
+                let message_id = match &e.message_id {
+                    Some(message_id) => message_id.clone(),
+                    None => self.current_text_chunk_id()?,
+                };
+
+                let existed = self.open_messages.contains_key(&message_id);
+                if !existed {
+                    self.open_message_order.push(message_id.clone());
+                }
+                let partial = self
+                    .open_messages
+                    .entry(message_id.clone())
+                    .or_insert_with(|| PartialMessage {
+                        role: e.role.clone(),
+                        content: String::new(),
+                        tool_calls: Vec::new(),
+                    });
+                if let Some(delta) = &e.delta {
+                    partial.content.push_str(delta);
+                }
+                if existed && e.delta.is_none() {
+                    // A chunk with no delta, after an entry already exists, signals the end.
+                    if let Some(partial) = self.open_messages.remove(&message_id) {
+                        self.open_message_order.retain(|id| id != &message_id);
+                        self.messages.push(finish_message(message_id, partial));
+                    }
+                }
+            }
+            Event::ThinkingTextMessageStart(_)
+            | Event::ThinkingTextMessageContent(_)
+            | Event::ThinkingTextMessageEnd(_) => {
+                // Thinking messages aren't part of the conversation transcript; nothing to fold.
+            }
+            Event::ToolCallStart(e) => {
+                self.open_tool_calls.insert(
+                    e.tool_call_id.clone(),
+                    PartialToolCall {
+                        name: e.tool_call_name.clone(),
+                        arguments: String::new(),
+                        parent_message_id: e.parent_message_id.clone(),
+                    },
+                );
+                self.open_tool_call_order.push(e.tool_call_id.clone());
+            }
+            Event::ToolCallArgs(e) => {
+                let partial = self
+                    .open_tool_calls
+                    .get_mut(&e.tool_call_id)
+                    .ok_or_else(|| ReduceError::UnknownToolCall(e.tool_call_id.clone()))?;
+                partial.arguments.push_str(&e.delta);
+            }
+            Event::ToolCallEnd(e) => {
+                let partial = self
+                    .open_tool_calls
+                    .remove(&e.tool_call_id)
+                    .ok_or_else(|| ReduceError::UnknownToolCall(e.tool_call_id.clone()))?;
+                self.open_tool_call_order
+                    .retain(|id| id != &e.tool_call_id);
+                self.attach_tool_call(e.tool_call_id.clone(), partial);
+            }
+            Event::ToolCallChunk(e) => {
+                let tool_call_id = match &e.tool_call_id {
+                    Some(tool_call_id) => tool_call_id.clone(),
+                    None => self.current_tool_call_chunk_id()?,
+                };
+
+                if !self.open_tool_calls.contains_key(&tool_call_id) {
+                    self.open_tool_call_order.push(tool_call_id.clone());
+                }
+                let partial = self
+                    .open_tool_calls
+                    .entry(tool_call_id.clone())
+                    .or_insert_with(|| PartialToolCall {
+                        name: e.tool_call_name.clone().unwrap_or_default(),
+                        arguments: String::new(),
+                        parent_message_id: e.parent_message_id.clone(),
+                    });
+                if let Some(delta) = &e.delta {
+                    partial.arguments.push_str(delta);
+                }
+                if e.delta.is_none() {
+                    if let Some(partial) = self.open_tool_calls.remove(&tool_call_id) {
+                        self.open_tool_call_order
+                            .retain(|id| id != &tool_call_id);
+                        self.attach_tool_call(tool_call_id, partial);
+                    }
+                }
+            }
+            Event::ToolCallResult(e) => {
+                self.messages.push(Message::Tool {
+                    id: e.message_id.clone(),
+                    content: MessageContent::text(e.content.clone()),
+                    tool_call_id: e.tool_call_id.clone(),
+                    error: None,
+                });
+            }
+            Event::ThinkingStart(_) | Event::ThinkingEnd(_) => {}
+            Event::StateSnapshot(e) => {
+                self.state = e.snapshot.clone();
+            }
+            Event::StateDelta(e) => {
+                self.state = apply_state_delta(&self.state, &e.delta)?;
+            }
+            Event::MessagesSnapshot(e) => {
+                self.messages = e.messages.clone();
+            }
+            Event::Raw(_) | Event::Custom(_) => {}
+            Event::RunStarted(_) | Event::RunFinished(_) | Event::RunError(_) => {}
+            Event::StepStarted(_) | Event::StepFinished(_) => {}
+        }
+
+        Ok(())
+    }
+
+    /// A `TextMessageChunk` with no `messageId` continues the most recently opened message.
+    fn current_text_chunk_id(&self) -> Result<MessageId, ReduceError> {
+        self.open_message_order
+            .last()
+            .cloned()
+            .ok_or(ReduceError::NoOpenChunkTarget)
+    }
+
+    /// A `ToolCallChunk` with no `toolCallId` continues the most recently opened tool call.
+    fn current_tool_call_chunk_id(&self) -> Result<ToolCallId, ReduceError> {
+        self.open_tool_call_order
+            .last()
+            .cloned()
+            .ok_or(ReduceError::NoOpenChunkTarget)
+    }
+
+    fn attach_tool_call(&mut self, id: ToolCallId, partial: PartialToolCall) {
+        let tool_call = ToolCall::new(
+            id,
+            FunctionCall {
+                name: partial.name,
+                arguments: partial.arguments,
+            },
+        );
+
+        if let Some(parent_id) = &partial.parent_message_id
+            && let Some(parent) = self.messages.iter_mut().find(|m| m.id() == parent_id)
+            && let Some(tool_calls) = parent.tool_calls_mut()
+        {
+            tool_calls.push(tool_call);
+            return;
+        }
+
+        let id = partial
+            .parent_message_id
+            .clone()
+            .unwrap_or_else(MessageId::random);
+        self.messages.push(Message::Assistant {
+            id,
+            content: None,
+            name: None,
+            tool_calls: Some(vec![tool_call]),
+        });
+    }
+}
+
+impl<StateT: AgentState> Default for EventReducer<StateT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn finish_message(id: MessageId, partial: PartialMessage) -> Message {
+    let content = MessageContent::text(partial.content);
+    match partial.role {
+        Role::Assistant => {
+            let mut message = Message::Assistant {
+                id,
+                content: Some(content),
+                name: None,
+                tool_calls: None,
+            };
+            if !partial.tool_calls.is_empty()
+                && let Some(tool_calls) = message.tool_calls_mut()
+            {
+                *tool_calls = partial.tool_calls;
+            }
+            message
+        }
+        role => Message::new(role, id, content),
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::error::{AgUiClientError, RateLimitMatcher};
+use crate::http::jittered_delay;
+
+/// Decides whether a failed [`crate::Agent::run_agent`] attempt should be retried, and how long
+/// to wait before the next one.
+///
+/// This sits above [`crate::error::RetryLogic`], which classifies a raw, not-yet-formatted HTTP
+/// outcome for [`crate::HttpAgent`]'s own initial-connect retry: [`RetryPolicy`] instead sees the
+/// plain [`AgUiClientError`] a caller already gets back from a run, so it can be used uniformly
+/// across every [`crate::Agent`] implementation (HTTP, WebSocket, or a test double), not just
+/// HTTP's connect phase. Implement this to customize which errors are worth another attempt --
+/// e.g. treating [`AgUiClientError::Subscriber`] as non-retryable even though it isn't transport
+/// related -- without forking the crate, mirroring how ethers/alloy let callers swap in their own
+/// retry layer over a transport.
+pub trait RetryPolicy: Send + Sync {
+    /// Whether the `attempt`-th failure (0-indexed) should be retried at all.
+    fn should_retry(&self, err: &AgUiClientError, attempt: u32) -> bool;
+
+    /// An explicit delay to wait before retrying, e.g. to honor a server-specified hint. `None`
+    /// (the default) defers to the caller's own exponential backoff schedule.
+    fn backoff_hint(&self, err: &AgUiClientError) -> Option<Duration> {
+        let _ = err;
+        None
+    }
+}
+
+/// The default [`RetryPolicy`]: retries whatever [`AgUiClientError::is_retryable_with`] already
+/// considers transient (per `rate_limit_matcher`), up to `max_retries` retries after the first
+/// attempt.
+#[derive(Debug, Clone)]
+pub struct DefaultRetryPolicy {
+    pub max_retries: u32,
+    pub rate_limit_matcher: RateLimitMatcher,
+}
+
+impl DefaultRetryPolicy {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            rate_limit_matcher: RateLimitMatcher::default(),
+        }
+    }
+
+    /// Use a custom [`RateLimitMatcher`] instead of its defaults, e.g. to recognize a specific
+    /// provider's rate-limit error codes.
+    pub fn with_rate_limit_matcher(mut self, matcher: RateLimitMatcher) -> Self {
+        self.rate_limit_matcher = matcher;
+        self
+    }
+}
+
+impl Default for DefaultRetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy for DefaultRetryPolicy {
+    fn should_retry(&self, err: &AgUiClientError, attempt: u32) -> bool {
+        attempt < self.max_retries && err.is_retryable_with(&self.rate_limit_matcher)
+    }
+}
+
+/// The exponential backoff schedule a [`RetryPolicy`] falls back to when
+/// [`RetryPolicy::backoff_hint`] returns `None`: `base * 2^attempt`, capped at `max_backoff`,
+/// with full jitter (a uniformly random duration in `[0, computed]`) to avoid synchronized
+/// retries across many clients.
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoff {
+    pub base: Duration,
+    pub max_backoff: Duration,
+}
+
+impl ExponentialBackoff {
+    pub fn new(base: Duration, max_backoff: Duration) -> Self {
+        Self { base, max_backoff }
+    }
+
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let computed = self.base.saturating_mul(1u32 << attempt.min(16));
+        jittered_delay(computed.min(self.max_backoff))
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(250), Duration::from_secs(10))
+    }
+}
@@ -0,0 +1,347 @@
+//! A GraphQL server that mirrors a single agent run for external dashboards, so a dashboard can
+//! observe progress without implementing [`AgentSubscriber`] itself.
+//!
+//! [`LiveRunSubscriber`] turns the `GenerativeUiSubscriber` plan-tracking pattern from the
+//! `generative_ui` example into a first-class observable service: it keeps a shared [`RunState`]
+//! current and forwards every event onto a channel, without knowing anything about GraphQL.
+//! [`LiveRunServer`] drains that channel, fans each event out to GraphQL subscribers, and serves
+//! a `current_run` query plus an `events` subscription over `async-graphql`.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use async_graphql::{Enum, Object, Schema, SimpleObject, Subscription};
+use futures::channel::mpsc;
+use futures::{Stream, StreamExt};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+
+use crate::agent::{AgentError, AgentStateMutation};
+use crate::core::event::{
+    Event, StateDeltaEvent, StateSnapshotEvent, StepFinishedEvent, StepStartedEvent,
+};
+use crate::core::{AgentState, FwdProps, JsonValue};
+use crate::subscriber::{AgentSubscriber, AgentSubscriberParams};
+
+/// Lifecycle status of a run mirrored by [`LiveRunSubscriber`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Enum)]
+pub enum RunStatus {
+    #[default]
+    Pending,
+    Running,
+    Finished,
+    Failed,
+}
+
+/// One named step within a run, mirroring the `GenerativeUiSubscriber` `Step`/`StepStatus` shape
+/// but driven by protocol `StepStarted`/`StepFinished` events instead of custom JSON state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Enum)]
+pub enum RunStepStatus {
+    #[default]
+    Started,
+    Finished,
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct RunStep {
+    pub name: String,
+    pub status: RunStepStatus,
+}
+
+/// A message flattened to plain text for dashboard display.
+#[derive(Debug, Clone, SimpleObject)]
+pub struct RunMessage {
+    pub role: String,
+    pub content: String,
+}
+
+/// A dashboard-friendly mirror of one agent run, kept current by [`LiveRunSubscriber`] and served
+/// by [`LiveRunServer`]'s `current_run` query.
+#[derive(Debug, Clone, Default, SimpleObject)]
+pub struct RunState {
+    pub status: RunStatus,
+    pub steps: Vec<RunStep>,
+    /// The message transcript as of the last `on_messages_changed` callback.
+    pub messages: Vec<RunMessage>,
+    /// Text accumulated by the in-progress assistant message, if any; cleared once it ends.
+    pub buffered_text: String,
+    /// The latest state snapshot/delta, JSON-encoded so the schema stays generic over `StateT`.
+    pub state_json: String,
+}
+
+/// An [`AgentSubscriber`] that mirrors a run into a shared [`RunState`] and forwards every event
+/// onto an unbounded channel, so a [`LiveRunServer`] can fan each one out to GraphQL subscribers
+/// without the subscriber itself knowing anything about GraphQL.
+///
+/// Construct with [`LiveRunSubscriber::new`], which returns both the subscriber and the receiver
+/// half of its event channel to hand to [`LiveRunServer::new`].
+pub struct LiveRunSubscriber<StateT: AgentState = JsonValue> {
+    state: Arc<Mutex<RunState>>,
+    events_tx: mpsc::UnboundedSender<Event<StateT>>,
+}
+
+impl<StateT: AgentState> LiveRunSubscriber<StateT> {
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<Event<StateT>>) {
+        let (events_tx, events_rx) = mpsc::unbounded();
+        (
+            Self {
+                state: Arc::new(Mutex::new(RunState::default())),
+                events_tx,
+            },
+            events_rx,
+        )
+    }
+
+    /// A handle to the live [`RunState`], shared with [`LiveRunServer::new`].
+    pub fn run_state(&self) -> Arc<Mutex<RunState>> {
+        Arc::clone(&self.state)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut RunState)) {
+        f(&mut self.state.lock().expect("LiveRunSubscriber state mutex poisoned"));
+    }
+
+    fn sync_state(&self, state: &StateT) {
+        let state_json = serde_json::to_string(state).unwrap_or_default();
+        self.update(|s| s.state_json = state_json);
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateT, FwdPropsT> AgentSubscriber<StateT, FwdPropsT> for LiveRunSubscriber<StateT>
+where
+    StateT: AgentState,
+    FwdPropsT: FwdProps,
+{
+    async fn on_run_initialized(
+        &self,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.update(|s| s.status = RunStatus::Running);
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_run_failed(
+        &self,
+        _error: &AgentError,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.update(|s| s.status = RunStatus::Failed);
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_run_finalized(
+        &self,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.update(|s| {
+            if s.status != RunStatus::Failed {
+                s.status = RunStatus::Finished;
+            }
+        });
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_step_started_event(
+        &self,
+        event: &StepStartedEvent,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.update(|s| {
+            s.steps.push(RunStep {
+                name: event.step_name.clone(),
+                status: RunStepStatus::Started,
+            })
+        });
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_step_finished_event(
+        &self,
+        event: &StepFinishedEvent,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.update(|s| {
+            if let Some(step) = s
+                .steps
+                .iter_mut()
+                .rev()
+                .find(|step| step.name == event.step_name && step.status == RunStepStatus::Started)
+            {
+                step.status = RunStepStatus::Finished;
+            }
+        });
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_text_message_content_event(
+        &self,
+        _event: &crate::core::event::TextMessageContentEvent,
+        text_message_buffer: &str,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        let buffer = text_message_buffer.to_string();
+        self.update(|s| s.buffered_text = buffer);
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_text_message_end_event(
+        &self,
+        _event: &crate::core::event::TextMessageEndEvent,
+        _text_message_buffer: &str,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.update(|s| s.buffered_text.clear());
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_state_snapshot_event(
+        &self,
+        _event: &StateSnapshotEvent<StateT>,
+        params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        self.sync_state(params.state);
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_state_delta_event(
+        &self,
+        _event: &StateDeltaEvent,
+        params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        // `params.state` already reflects the patch -- the event handler applies `StateDelta`
+        // before invoking subscribers, same as it does for `StateSnapshot`.
+        self.sync_state(params.state);
+        Ok(AgentStateMutation::default())
+    }
+
+    async fn on_messages_changed(
+        &self,
+        params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<(), AgentError> {
+        let messages = params
+            .messages
+            .iter()
+            .map(|m| RunMessage {
+                role: format!("{:?}", m.role()).to_lowercase(),
+                content: m.content().unwrap_or_default(),
+            })
+            .collect();
+        self.update(|s| s.messages = messages);
+        Ok(())
+    }
+
+    async fn on_event(
+        &self,
+        event: &Event<StateT>,
+        _params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        // Best-effort: a closed receiver (the server shutting down) shouldn't fail the run.
+        let _ = self.events_tx.unbounded_send(event.clone());
+        Ok(AgentStateMutation::default())
+    }
+}
+
+/// Query root exposing the mirrored [`RunState`].
+struct QueryRoot {
+    state: Arc<Mutex<RunState>>,
+}
+
+#[Object]
+impl QueryRoot {
+    /// The current mirrored run: status, steps, messages, and buffered text.
+    async fn current_run(&self) -> RunState {
+        self.state
+            .lock()
+            .expect("LiveRunSubscriber state mutex poisoned")
+            .clone()
+    }
+}
+
+/// Subscription root streaming each event as it arrives, JSON-encoded so the schema stays
+/// generic over `StateT` without needing a GraphQL representation for every event variant.
+struct SubscriptionRoot {
+    events: broadcast::Sender<String>,
+}
+
+#[Subscription]
+impl SubscriptionRoot {
+    async fn events(&self) -> impl Stream<Item = String> {
+        BroadcastStream::new(self.events.subscribe()).filter_map(|item| async move { item.ok() })
+    }
+}
+
+/// The concrete schema served by [`LiveRunServer`].
+pub type LiveRunSchema = Schema<QueryRoot, async_graphql::EmptyMutation, SubscriptionRoot>;
+
+/// GraphQL server that mirrors a single agent run for external dashboards.
+///
+/// Pairs with a [`LiveRunSubscriber`]: build one via [`LiveRunSubscriber::new`], run the agent
+/// with it attached, and hand [`LiveRunServer::new`] the subscriber's `run_state()` handle and
+/// the receiver half of its event channel. [`LiveRunServer::serve`] then binds a configurable
+/// address and starts answering `current_run` queries and `events` subscriptions.
+pub struct LiveRunServer {
+    schema: LiveRunSchema,
+}
+
+impl LiveRunServer {
+    /// Builds the server and spawns the watch task that drains `events` into the GraphQL
+    /// `events` subscription fan-out; `run_state` is read directly, already kept current by
+    /// [`LiveRunSubscriber`].
+    pub fn new<StateT: AgentState>(
+        run_state: Arc<Mutex<RunState>>,
+        mut events: mpsc::UnboundedReceiver<Event<StateT>>,
+    ) -> Self {
+        let (events_tx, _rx) = broadcast::channel(256);
+        let broadcast_tx = events_tx.clone();
+        tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                if let Ok(json) = serde_json::to_string(&event) {
+                    // No receivers yet is fine -- the event is simply not replayed to anyone.
+                    let _ = broadcast_tx.send(json);
+                }
+            }
+        });
+
+        let schema = Schema::build(
+            QueryRoot { state: run_state },
+            async_graphql::EmptyMutation,
+            SubscriptionRoot { events: events_tx },
+        )
+        .finish();
+
+        Self { schema }
+    }
+
+    /// Serve the GraphQL schema (queries and subscriptions; mutations are unused) over HTTP at
+    /// `addr`, via `async-graphql`'s Axum integration.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), AgentError> {
+        let app = axum::Router::new()
+            .route(
+                "/graphql",
+                axum::routing::get_service(async_graphql_axum::GraphQLSubscription::new(
+                    self.schema.clone(),
+                ))
+                .post(graphql_handler),
+            )
+            .with_state(self.schema);
+
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .map_err(|e| AgentError::Transport {
+                message: format!("failed to bind live-run server to {addr}: {e}"),
+            })?;
+        axum::serve(listener, app)
+            .await
+            .map_err(|e| AgentError::Transport {
+                message: format!("live-run server error: {e}"),
+            })
+    }
+}
+
+async fn graphql_handler(
+    axum::extract::State(schema): axum::extract::State<LiveRunSchema>,
+    req: async_graphql_axum::GraphQLRequest,
+) -> async_graphql_axum::GraphQLResponse {
+    schema.execute(req.into_inner()).await.into()
+}
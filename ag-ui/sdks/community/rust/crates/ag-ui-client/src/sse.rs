@@ -4,6 +4,7 @@ use bytes::Bytes;
 use futures::{Stream, StreamExt};
 use reqwest::Response;
 use std::pin::Pin;
+use std::time::Duration;
 
 /// Represents a parsed Server-Sent Event
 #[derive(Debug)]
@@ -16,6 +17,9 @@ pub struct SseEvent {
 
     /// The event data (from the "data:" field)
     pub data: String,
+
+    /// The server-suggested reconnection delay in milliseconds (from the "retry:" field)
+    pub retry: Option<u64>,
 }
 
 /// Extension trait for processing Server-Sent Events (SSE) responses from reqwest::Response
@@ -55,14 +59,68 @@ impl SseResponseExt for Response {
     async fn event_source(
         self,
     ) -> Pin<Box<dyn Stream<Item = Result<SseEvent, AgUiClientError>> + Send>> {
-        // Create a stream of bytes from the response
-        let stream = self.bytes_stream();
+        // Create a stream of bytes from the response, tagging transport errors as such. A
+        // mid-stream error caused by a configured `with_timeout`/`with_connect_timeout` elapsing
+        // (see `HttpAgentBuilder`) is reported as `AgUiClientError::Timeout` rather than the
+        // generic `HttpTransport`, so callers can tell a bounded-time cutoff apart from a genuine
+        // connection failure.
+        let stream = self.bytes_stream().map(|chunk_result| {
+            chunk_result.map_err(|e| {
+                if e.is_timeout() {
+                    AgUiClientError::Timeout {
+                        message: e.to_string(),
+                    }
+                } else {
+                    AgUiClientError::HttpTransport(e)
+                }
+            })
+        });
 
         // Process the stream with type conversions
         Box::pin(SseEventProcessor::new(stream))
     }
 }
 
+/// Scans an arbitrary byte stream (not just a `reqwest::Response`) for SSE events, e.g. the raw
+/// response body of a request sent over a transport `reqwest` doesn't support, like a Unix
+/// domain socket (see [`crate::uds`]).
+pub(crate) fn sse_event_stream(
+    stream: impl Stream<Item = Result<Bytes, AgUiClientError>> + Send + 'static,
+) -> impl Stream<Item = Result<SseEvent, AgUiClientError>> + Send {
+    SseEventProcessor::new(stream)
+}
+
+/// Configuration for [`crate::http::HttpAgentBuilder::with_resumable_stream`]'s reconnect loop.
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// Maximum number of consecutive reconnect attempts before giving up.
+    pub max_retries: u32,
+    /// Starting point for exponential backoff on repeated reconnect failures. A server's `retry:`
+    /// field is parsed into [`SseEvent::retry`] but is not used for reconnect timing.
+    pub default_delay: Duration,
+    /// Upper bound on the backoff delay, however far doubling `default_delay` would take it.
+    pub max_delay: Duration,
+    /// Opt-in: also reconnect when the stream itself (not the transport) reports a terminal
+    /// error -- an `Event::RunError` payload or a malformed frame -- as long as it happens before
+    /// any other event has reached the caller for this run. Once an event has been delivered the
+    /// run may no longer be idempotent, so a later in-band error always ends the stream instead,
+    /// regardless of this setting. Off by default: a `200` followed by an in-band error is, for
+    /// most servers, a genuine application failure rather than a transient one worth retrying.
+    /// See [`crate::http::HttpAgentBuilder::with_resumable_stream`].
+    pub retry_on_stream_error: bool,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            default_delay: Duration::from_millis(1000),
+            max_delay: Duration::from_secs(30),
+            retry_on_stream_error: false,
+        }
+    }
+}
+
 /// A processor that converts a byte stream into an SSE event stream
 struct SseEventProcessor;
 
@@ -70,84 +128,115 @@ impl SseEventProcessor {
     /// Creates a new SSE event processor
     #[allow(clippy::new_ret_no_self)]
     fn new(
-        stream: impl Stream<Item = Result<Bytes, reqwest::Error>> + 'static,
+        stream: impl Stream<Item = Result<Bytes, AgUiClientError>> + 'static,
     ) -> impl Stream<Item = Result<SseEvent, AgUiClientError>> {
-        let mut buffer = String::new();
+        let mut scanner = IncrementalSseScanner::new();
 
         // Process the stream
         stream
             .map(move |chunk_result| {
-                // Map reqwest errors
                 let chunk = match chunk_result {
                     Ok(chunk) => chunk,
-                    Err(err) => return vec![Err(AgUiClientError::HttpTransport(err))],
+                    Err(err) => return vec![Err(err)],
                 };
 
-                // Convert bytes to string and append to buffer
-                match String::from_utf8(chunk.to_vec()) {
-                    Ok(text) => {
-                        buffer.push_str(&text);
-
-                        // Process complete events from the buffer
-                        let (events, new_buffer) = process_raw_sse_events(&buffer);
-                        buffer = new_buffer;
-
-                        events
-                    }
-                    Err(e) => vec![Err(AgUiClientError::SseParse {
-                        message: format!("Invalid UTF-8: {e}"),
-                    })],
-                }
+                scanner.push(&chunk)
             })
             .flat_map(futures::stream::iter)
     }
 }
 
-/// Process SSE data from a buffer string into raw SSE events
+/// Scans an append-only byte buffer for `\n\n`-delimited SSE events in amortized linear time.
 ///
-/// Returns a tuple of (events, new_buffer) where:
-/// - events: A vector of parsed events or errors
-/// - new_buffer: The remaining buffer that might contain incomplete events
-fn process_raw_sse_events(buffer: &str) -> (Vec<Result<SseEvent, AgUiClientError>>, String) {
-    let mut results = Vec::new();
-    let chunks: Vec<&str> = buffer.split("\n\n").collect();
-
-    // If there's only one chunk and it doesn't end with a double newline,
-    // it might be incomplete - keep it in the buffer
-    if chunks.len() == 1 && !buffer.ends_with("\n\n") {
-        return (Vec::new(), buffer.to_string());
+/// The naive approach -- re-splitting the whole accumulated buffer on every incoming chunk --
+/// does O(n^2) work in the total bytes seen by a long-lived stream. This scanner instead keeps
+/// a `scan_from` cursor marking how far it has already searched with no match, so each chunk
+/// only extends the search over the bytes it actually added (plus a one-byte overlap in case a
+/// `\n\n` straddles the chunk boundary). Buffering raw bytes rather than decoding each chunk to
+/// `String` also means a multibyte UTF-8 character split across two chunks is never inspected
+/// until the full event around it has arrived, so it can no longer trigger a spurious decode
+/// error.
+struct IncrementalSseScanner {
+    buffer: Vec<u8>,
+    /// How many leading bytes of `buffer` have already been sliced off into emitted events.
+    consumed: usize,
+    /// How far into `buffer` we've already searched for the next `\n\n` with no match.
+    scan_from: usize,
+}
+
+/// Above this many already-consumed bytes, compact the buffer even if it's not yet half full,
+/// so memory doesn't grow unbounded on a stream with rare, large events.
+const COMPACT_THRESHOLD_BYTES: usize = 64 * 1024;
+
+impl IncrementalSseScanner {
+    fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            consumed: 0,
+            scan_from: 0,
+        }
     }
 
-    let complete_chunks = if buffer.ends_with("\n\n") {
-        // All chunks are complete
-        &chunks[..]
-    } else {
-        // Last chunk might be incomplete
-        &chunks[..chunks.len() - 1]
-    };
-
-    // Process all complete events
-    for chunk in complete_chunks {
-        if !chunk.is_empty() {
-            results.push(parse_sse_event(chunk));
+    /// Feed a new chunk of bytes, returning every SSE event it completed.
+    fn push(&mut self, chunk: &[u8]) -> Vec<Result<SseEvent, AgUiClientError>> {
+        self.buffer.extend_from_slice(chunk);
+        let mut results = Vec::new();
+
+        loop {
+            let search_start = self.scan_from.max(self.consumed);
+            match find_double_newline(&self.buffer[search_start..]) {
+                Some(offset) => {
+                    let boundary = search_start + offset;
+                    let event_bytes = &self.buffer[self.consumed..boundary];
+                    if !event_bytes.is_empty() {
+                        results.push(decode_event_slice(event_bytes));
+                    }
+                    self.consumed = boundary + 2;
+                    self.scan_from = self.consumed;
+                }
+                None => {
+                    // Back off one byte so a "\n\n" split across this chunk and the next is
+                    // still found, without re-scanning everything already ruled out.
+                    self.scan_from = self.buffer.len().saturating_sub(1).max(self.consumed);
+                    break;
+                }
+            }
         }
+
+        self.compact_if_needed();
+        results
     }
 
-    // If the buffer doesn't end with a double newline and we have chunks,
-    // the last chunk is incomplete - keep it in the buffer
-    let new_buffer = if !buffer.ends_with("\n\n") && !chunks.is_empty() {
-        chunks.last().unwrap().to_string()
-    } else {
-        String::new()
-    };
+    /// Drain the already-emitted prefix once it dominates the buffer, so compaction itself
+    /// stays amortized linear rather than happening on every chunk.
+    fn compact_if_needed(&mut self) {
+        if self.consumed == 0 {
+            return;
+        }
+        if self.consumed >= self.buffer.len() / 2 || self.consumed >= COMPACT_THRESHOLD_BYTES {
+            self.buffer.drain(..self.consumed);
+            self.scan_from -= self.consumed;
+            self.consumed = 0;
+        }
+    }
+}
 
-    (results, new_buffer)
+fn find_double_newline(haystack: &[u8]) -> Option<usize> {
+    haystack.windows(2).position(|w| w == b"\n\n")
+}
+
+fn decode_event_slice(bytes: &[u8]) -> Result<SseEvent, AgUiClientError> {
+    let text = std::str::from_utf8(bytes).map_err(|e| AgUiClientError::SseParse {
+        message: format!("Invalid UTF-8: {e}"),
+    })?;
+    parse_sse_event(text)
 }
 
 /// Parse a single SSE event text into an SseEvent
 fn parse_sse_event(event_text: &str) -> Result<SseEvent, AgUiClientError> {
     let mut event = None;
     let mut id = None;
+    let mut retry = None;
     let mut data_lines = Vec::new();
 
     for line in event_text.lines() {
@@ -163,60 +252,25 @@ fn parse_sse_event(event_text: &str) -> Result<SseEvent, AgUiClientError> {
             // For data lines, trim a leading space if present
             let data_content = value.strip_prefix(" ").unwrap_or(value);
             data_lines.push(data_content);
+        } else if let Some(value) = line.strip_prefix("retry:") {
+            retry = value.trim().parse::<u64>().ok();
         }
-        // Ignore other fields like "retry:"
     }
 
     // Join all data lines with newlines
     let data = data_lines.join("\n");
 
-    Ok(SseEvent { event, id, data })
+    Ok(SseEvent {
+        event,
+        id,
+        data,
+        retry,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde::Deserialize;
-
-    #[derive(Deserialize, Debug, PartialEq)]
-    struct TestEvent {
-        event_type: String,
-        data: String,
-    }
-
-    #[tokio::test]
-    async fn test_process_raw_sse_events() {
-        // Test with a single complete event
-        let buffer = "data: {\"event_type\":\"test\",\"data\":\"hello\"}\n\n";
-        let (events, new_buffer) = process_raw_sse_events(buffer);
-        assert_eq!(events.len(), 1);
-        assert_eq!(new_buffer, "");
-        let event = events[0].as_ref().unwrap();
-        assert_eq!(event.data, "{\"event_type\":\"test\",\"data\":\"hello\"}");
-
-        // Test with multiple events
-        let buffer = "data: {\"event_type\":\"test1\",\"data\":\"hello1\"}\n\n\
-                      data: {\"event_type\":\"test2\",\"data\":\"hello2\"}\n\n";
-        let (events, new_buffer) = process_raw_sse_events(buffer);
-        assert_eq!(events.len(), 2);
-        assert_eq!(new_buffer, "");
-
-        // Test with incomplete event
-        let buffer = "data: {\"event_type\":\"test\",\"data\":\"hello\"}";
-        let (events, new_buffer) = process_raw_sse_events(buffer);
-        assert_eq!(events.len(), 0);
-        assert_eq!(new_buffer, buffer);
-
-        // Test with complete and incomplete events
-        let buffer = "data: {\"event_type\":\"test1\",\"data\":\"hello1\"}\n\n\
-                      data: {\"event_type\":\"test2\",\"data\":\"hello2\"}";
-        let (events, new_buffer) = process_raw_sse_events(buffer);
-        assert_eq!(events.len(), 1);
-        assert_eq!(
-            new_buffer,
-            "data: {\"event_type\":\"test2\",\"data\":\"hello2\"}"
-        );
-    }
 
     #[tokio::test]
     async fn test_parse_sse_event() {
@@ -241,95 +295,55 @@ mod tests {
         assert_eq!(sse_event.data, "line 1\nline 2\nline 3");
     }
 
-    #[tokio::test]
-    async fn test_different_event_types() {
-        // Define different data structures for different event types
-        #[derive(Deserialize, Debug, PartialEq)]
-        struct PingData {
-            message: String,
-        }
+    #[test]
+    fn incremental_scanner_assembles_event_split_across_many_small_chunks() {
+        let mut scanner = IncrementalSseScanner::new();
+        let full = "event: update\ndata: {\"id\":1}\n\n";
 
-        #[derive(Deserialize, Debug, PartialEq)]
-        struct UpdateData {
-            id: u32,
-            status: String,
+        let mut results = Vec::new();
+        for byte in full.as_bytes() {
+            results.extend(scanner.push(&[*byte]));
         }
 
-        // Create a buffer with different event types
-        let buffer = "event: ping\ndata: {\"message\":\"hello\"}\n\n\
-                      event: update\ndata: {\"id\":123,\"status\":\"ok\"}\n\n";
-
-        // Process the raw events
-        let (raw_events, new_buffer) = process_raw_sse_events(buffer);
-        assert_eq!(raw_events.len(), 2);
-        assert_eq!(new_buffer, "");
+        assert_eq!(results.len(), 1);
+        let event = results[0].as_ref().unwrap();
+        assert_eq!(event.event, Some("update".to_string()));
+        assert_eq!(event.data, "{\"id\":1}");
+    }
 
-        // Process each event based on its type
-        let ping_event = raw_events[0].as_ref().unwrap();
-        let update_event = raw_events[1].as_ref().unwrap();
+    #[test]
+    fn incremental_scanner_handles_multibyte_char_split_across_chunks() {
+        let mut scanner = IncrementalSseScanner::new();
+        // "café" has a 2-byte UTF-8 character ('é'); split the chunk right inside it.
+        let full = "data: caf\u{e9}\n\n".as_bytes().to_vec();
+        let split_at = full.len() - 3;
 
-        assert_eq!(ping_event.event, Some("ping".to_string()));
-        assert_eq!(update_event.event, Some("update".to_string()));
+        let mut results = scanner.push(&full[..split_at]);
+        assert!(results.is_empty());
+        results.extend(scanner.push(&full[split_at..]));
 
-        // Deserialize the ping event
-        let ping_data: PingData = serde_json::from_str(&ping_event.data).unwrap();
-        assert_eq!(
-            ping_data,
-            PingData {
-                message: "hello".to_string()
-            }
-        );
-
-        // Deserialize the update event
-        let update_data: UpdateData = serde_json::from_str(&update_event.data).unwrap();
-        assert_eq!(
-            update_data,
-            UpdateData {
-                id: 123,
-                status: "ok".to_string()
-            }
-        );
+        assert_eq!(results.len(), 1);
+        let event = results[0].as_ref().unwrap();
+        assert_eq!(event.data, "caf\u{e9}");
     }
 
-    #[tokio::test]
-    async fn test_enum_event_types() {
-        // Define an enum for event types
-        #[derive(Deserialize, Debug, PartialEq)]
-        #[serde(rename_all = "lowercase")]
-        enum EventType {
-            Ping,
-            Update,
-            Message,
-        }
+    #[test]
+    fn incremental_scanner_handles_multiple_events_in_one_chunk() {
+        let mut scanner = IncrementalSseScanner::new();
+        let chunk = "event: a\ndata: 1\n\nevent: b\ndata: 2\n\n";
 
-        // Define a data structure
-        #[derive(Deserialize, Debug, PartialEq)]
-        struct EventData {
-            value: String,
-        }
+        let results = scanner.push(chunk.as_bytes());
 
-        // Test direct deserialization with stream_with_types
-        let buffer = "event: ping\ndata: {\"value\":\"ping data\"}\n\n\
-                      event: update\ndata: {\"value\":\"update data\"}\n\n\
-                      event: message\ndata: {\"value\":\"message data\"}\n\n";
-
-        // Process the raw events
-        let (raw_events, _) = process_raw_sse_events(buffer);
-        assert_eq!(raw_events.len(), 3);
-
-        // Parse event types as enum values
-        for raw_event in raw_events {
-            let sse_event = raw_event.unwrap();
-            let event_type: EventType =
-                serde_json::from_str(&format!("\"{}\"", sse_event.event.unwrap())).unwrap();
-            let data: EventData = serde_json::from_str(&sse_event.data).unwrap();
-
-            // Verify the event type matches the expected enum variant
-            match event_type {
-                EventType::Ping => assert_eq!(data.value, "ping data"),
-                EventType::Update => assert_eq!(data.value, "update data"),
-                EventType::Message => assert_eq!(data.value, "message data"),
-            }
-        }
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().data, "1");
+        assert_eq!(results[1].as_ref().unwrap().data, "2");
+    }
+
+    #[test]
+    fn parse_sse_event_captures_retry_field() {
+        let event_text = "event: ping\nretry: 2500\ndata: {}";
+        let sse_event = parse_sse_event(event_text).unwrap();
+        assert_eq!(sse_event.retry, Some(2500));
     }
+
 }
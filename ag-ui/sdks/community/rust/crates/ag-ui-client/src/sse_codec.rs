@@ -0,0 +1,100 @@
+use futures::{Stream, StreamExt};
+
+use crate::core::event::{Event, EventType};
+use crate::core::{AgentState, JsonValue};
+use crate::error::AgUiClientError;
+use crate::sse::SseEvent;
+
+/// Errors decoding a raw [`SseEvent`] into a typed [`Event`].
+#[derive(Debug, thiserror::Error)]
+pub enum SseDecodeError {
+    /// The SSE frame's `event:` name didn't match any known [`EventType`].
+    #[error("SSE event name {0:?} does not match any known AG-UI EventType")]
+    UnknownEventType(String),
+
+    /// The `data:` payload didn't parse as the tagged `Event` JSON shape.
+    #[error("failed to decode event data as an AG-UI Event: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The underlying SSE/transport stream itself failed before a frame could be decoded.
+    #[error(transparent)]
+    Transport(#[from] AgUiClientError),
+}
+
+/// Decodes a single raw [`SseEvent`] into a typed `Event<StateT>`.
+///
+/// `Event`'s own `#[serde(tag = "type")]` representation means `data:` alone fully determines
+/// the event, so the SSE `event:` field (when present) is only cross-checked against a known
+/// [`EventType`] name rather than driving the decode itself -- a malformed or unrecognized
+/// `event:` name is still surfaced as an error instead of silently ignored.
+pub fn decode_sse_event<StateT: AgentState>(
+    raw: &SseEvent,
+) -> Result<Event<StateT>, SseDecodeError> {
+    if let Some(name) = &raw.event {
+        serde_json::from_value::<EventType>(JsonValue::String(name.clone()))
+            .map_err(|_| SseDecodeError::UnknownEventType(name.clone()))?;
+    }
+    Ok(serde_json::from_str(&raw.data)?)
+}
+
+/// Adapts a stream of raw [`SseEvent`]s (e.g. from [`crate::sse::SseResponseExt::event_source`])
+/// into a stream of strongly-typed `Event<StateT>` values, so downstream code matches on `Event`
+/// variants instead of string-matching `event:` names.
+pub fn events_typed<StateT: AgentState>(
+    events: impl Stream<Item = Result<SseEvent, AgUiClientError>> + Send,
+) -> impl Stream<Item = Result<Event<StateT>, SseDecodeError>> + Send {
+    events.map(|result| match result {
+        Ok(raw) => decode_sse_event(&raw),
+        Err(err) => Err(err.into()),
+    })
+}
+
+/// Encodes an `Event<StateT>` into a single, properly framed SSE block: `event: <TYPE>`,
+/// `id: <id>` (when given), one or more `data:` lines (the JSON payload split on any embedded
+/// newline), and the trailing blank line that terminates an SSE event.
+pub fn encode_sse_event<StateT: AgentState>(
+    event: &Event<StateT>,
+    id: Option<&str>,
+) -> Result<String, serde_json::Error> {
+    let data = serde_json::to_string(event)?;
+    let event_type = event_type_name(event.event_type())?;
+
+    let mut block = String::new();
+    block.push_str("event: ");
+    block.push_str(&event_type);
+    block.push('\n');
+
+    if let Some(id) = id {
+        block.push_str("id: ");
+        block.push_str(id);
+        block.push('\n');
+    }
+
+    for line in data.split('\n') {
+        block.push_str("data: ");
+        block.push_str(line);
+        block.push('\n');
+    }
+
+    block.push('\n');
+    Ok(block)
+}
+
+/// Encodes a stream of `Event<StateT>` values into SSE-framed text blocks, ready to be written
+/// directly to a `text/event-stream` response body.
+pub fn encode_sse<StateT: AgentState>(
+    events: impl Stream<Item = Event<StateT>> + Send,
+) -> impl Stream<Item = Result<String, serde_json::Error>> + Send {
+    events.map(|event| encode_sse_event(&event, None))
+}
+
+/// Renders an [`EventType`] the same way `Event`'s own `#[serde(rename_all = "SCREAMING_SNAKE_CASE")]`
+/// tag does, for the SSE `event:` field.
+fn event_type_name(event_type: EventType) -> Result<String, serde_json::Error> {
+    // `EventType` only (de)serializes through serde, so round-trip through a JSON string rather
+    // than hand-maintaining a second SCREAMING_SNAKE_CASE table that could drift from the derive.
+    match serde_json::to_value(event_type)? {
+        JsonValue::String(s) => Ok(s),
+        _ => unreachable!("EventType serializes as a string"),
+    }
+}
@@ -1,14 +1,77 @@
 #![allow(unused)]
 
 use std::collections::HashMap;
+use std::ops::BitOr;
 use std::slice::Iter;
 use std::sync::Arc;
 
-use crate::agent::{AgentError, AgentStateMutation};
+use crate::agent::{AgentError, AgentStateMutation, TransportRetryEvent};
 use crate::core::event::*;
 use crate::core::types::{Message, RunAgentInput, ToolCall};
 use crate::core::{AgentState, FwdProps, JsonValue};
 
+/// A bitset of [`EventType`]s an [`AgentSubscriber`] wants dispatched to it; see
+/// [`AgentSubscriber::interests`].
+///
+/// The dispatcher in `event_handler` consults this before invoking a per-event-type callback (and
+/// before doing any work -- like buffering partial text/tool-call content -- that exists only to
+/// hand that callback something to read), so a subscriber that only implements
+/// `on_state_delta_event` never pays for the other ~25 callbacks' dispatch or their
+/// `AgentStateMutation::default()` allocation. [`Subscribers`] precomputes the union of every
+/// registered subscriber's interests so the run loop can skip that shared buffering work entirely
+/// when nobody wants it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventInterest(u32);
+
+impl EventInterest {
+    /// No event kinds.
+    pub const NONE: Self = Self(0);
+    /// Every event kind -- the default, matching today's "dispatch everything" behavior.
+    pub const ALL: Self = Self(u32::MAX);
+
+    /// Interest in a single [`EventType`].
+    pub fn of(event_type: EventType) -> Self {
+        Self(1 << event_type as u32)
+    }
+
+    /// Interest in several [`EventType`]s at once.
+    pub fn of_types(event_types: impl IntoIterator<Item = EventType>) -> Self {
+        event_types.into_iter().map(Self::of).fold(Self::NONE, Self::union)
+    }
+
+    /// Combines two interest sets.
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Whether `event_type` is in this set.
+    pub fn contains(self, event_type: EventType) -> bool {
+        self.0 & Self::of(event_type).0 != 0
+    }
+}
+
+impl Default for EventInterest {
+    /// Matches the pre-[`EventInterest`] behavior: a subscriber that doesn't override
+    /// [`AgentSubscriber::interests`] still receives every event.
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for EventInterest {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl FromIterator<EventType> for EventInterest {
+    fn from_iter<I: IntoIterator<Item = EventType>>(iter: I) -> Self {
+        Self::of_types(iter)
+    }
+}
+
 pub struct AgentSubscriberParams<'a, StateT: AgentState, FwdPropsT: FwdProps> {
     pub messages: &'a [Message],
     pub state: &'a StateT,
@@ -46,6 +109,17 @@ where
         Ok(AgentStateMutation::default())
     }
 
+    /// Called once per failed mid-run transport attempt, right before the agent waits out
+    /// `retry.delay` and reconnects. Fires zero or more times; if every retry is exhausted the
+    /// run still ends with `on_run_failed` as usual.
+    async fn on_transport_retry(
+        &self,
+        retry: &TransportRetryEvent,
+        params: AgentSubscriberParams<'async_trait, StateT, FwdPropsT>,
+    ) -> Result<AgentStateMutation<StateT>, AgentError> {
+        Ok(AgentStateMutation::default())
+    }
+
     // Events
     async fn on_event(
         &self,
@@ -284,6 +358,14 @@ where
     ) -> Result<(), AgentError> {
         Ok(())
     }
+
+    /// Declares which [`EventType`]s this subscriber wants dispatched to it via its per-event-type
+    /// callbacks (`on_state_delta_event`, `on_text_message_content_event`, etc.) -- see
+    /// [`EventInterest`]. Defaults to [`EventInterest::ALL`], matching the behavior of a
+    /// subscriber that doesn't override this.
+    fn interests(&self) -> EventInterest {
+        EventInterest::ALL
+    }
 }
 
 /// Wrapper for subscriber implementations.
@@ -316,6 +398,9 @@ where
 #[derive(Clone)]
 pub struct Subscribers<StateT: AgentState = JsonValue, FwdPropsT: FwdProps = JsonValue> {
     subs: Vec<Arc<dyn AgentSubscriber<StateT, FwdPropsT>>>,
+    /// The union of every subscriber's [`AgentSubscriber::interests`], precomputed once here so
+    /// the run loop can check it instead of re-deriving it on every event.
+    interests: EventInterest,
 }
 
 impl<StateT, FwdPropsT> Subscribers<StateT, FwdPropsT>
@@ -324,7 +409,19 @@ where
     FwdPropsT: FwdProps,
 {
     pub fn new(subscribers: Vec<Arc<dyn AgentSubscriber<StateT, FwdPropsT>>>) -> Self {
-        Self { subs: subscribers }
+        let interests = subscribers
+            .iter()
+            .map(|s| s.interests())
+            .fold(EventInterest::NONE, EventInterest::union);
+        Self {
+            subs: subscribers,
+            interests,
+        }
+    }
+
+    /// The union of every registered subscriber's [`AgentSubscriber::interests`].
+    pub fn interests(&self) -> EventInterest {
+        self.interests
     }
 
     /// Creates a new Subscribers collection from a single subscriber
@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use std::thread::available_parallelism;
+
+use futures::future::BoxFuture;
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use crate::agent::{Agent, RunAgentParams, RunAgentResult};
+use crate::core::event::{BaseEvent, RunErrorEvent, StepFinishedEvent, StepStartedEvent};
+use crate::core::types::{
+    Message, MessageContent, MessageId, RunAgentInput, RunId, ThreadId, Tool, ToolCall,
+    ToolCallId, ToolMessage,
+};
+use crate::core::{AgentState, FwdProps, JsonValue};
+use crate::error::AgUiClientError as AgentError;
+use crate::subscriber::{AgentSubscriberParams, IntoSubscribers, Subscribers};
+
+/// An async handler invoked with a tool call's parsed arguments, returning the JSON result to
+/// feed back to the agent (or an error describing why it couldn't run).
+pub type ToolHandler =
+    Box<dyn Fn(JsonValue) -> BoxFuture<'static, Result<JsonValue, ToolHandlerError>> + Send + Sync>;
+
+/// An error raised by a [`ToolHandler`] while executing a tool call.
+pub type ToolHandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Errors produced while driving a [`ToolLoop`].
+#[derive(Debug, thiserror::Error)]
+pub enum ToolLoopError {
+    /// The underlying agent run itself failed.
+    #[error("agent run failed: {0}")]
+    Agent(#[from] AgentError),
+
+    /// The agent requested a tool that wasn't registered with [`ToolLoopBuilder::register`].
+    #[error("agent requested an unregistered tool {0:?}")]
+    UnknownTool(String),
+
+    /// The accumulated `ToolCallArgs` deltas didn't parse as JSON.
+    #[error("arguments for tool call {tool_call_id} did not parse as JSON: {source}")]
+    ArgsJson {
+        tool_call_id: ToolCallId,
+        source: serde_json::Error,
+    },
+
+    /// The parsed arguments didn't satisfy the tool's `parameters` schema.
+    #[error("arguments for tool {tool:?} failed schema validation: {reason}")]
+    SchemaValidation { tool: String, reason: String },
+
+    /// The registered handler for a tool returned an error.
+    #[error("handler for tool {tool:?} failed: {source}")]
+    ToolHandler {
+        tool: String,
+        source: ToolHandlerError,
+    },
+
+    /// The loop ran for `max_steps` rounds without the agent stopping its tool requests.
+    #[error("tool loop exceeded its configured max_steps ({max_steps})")]
+    MaxStepsExceeded { max_steps: u32 },
+}
+
+/// Drives multi-step function calling: whenever an agent run ends with pending tool calls, each
+/// registered [`Tool`]'s arguments are validated against its `parameters` schema, its handler is
+/// invoked, and the result is fed back into the conversation as a `tool` [`Message`] before the
+/// agent is run again. Iterates until a round produces no pending tool calls, or `max_steps`
+/// rounds have elapsed.
+///
+/// This builds on [`Agent::run_agent`] rather than re-deriving tool-call bookkeeping from the raw
+/// event stream: `run_agent`'s [`crate::event_handler::EventHandler`] already folds
+/// `ToolCallStart`/`ToolCallArgs`/`ToolCallEnd` into an [`Assistant`](Message::Assistant)
+/// message's `tool_calls`, so each round of this loop only has to look at `new_messages` for
+/// `tool_calls` nothing has replied to yet.
+pub struct ToolLoop<StateT: AgentState = JsonValue> {
+    tools: HashMap<String, (Tool, ToolHandler)>,
+    max_steps: u32,
+    _state: std::marker::PhantomData<StateT>,
+}
+
+impl<StateT: AgentState> ToolLoop<StateT> {
+    pub fn builder() -> ToolLoopBuilder<StateT> {
+        ToolLoopBuilder::new()
+    }
+
+    /// The registered [`Tool`] definitions, to pass along via [`RunAgentParams::tools`].
+    pub fn tools(&self) -> Vec<Tool> {
+        self.tools.values().map(|(tool, _)| tool.clone()).collect()
+    }
+
+    /// Runs `agent` to completion, executing registered tools in between rounds as needed.
+    pub async fn run<A, FwdPropsT>(
+        &self,
+        agent: &A,
+        mut params: RunAgentParams<StateT, FwdPropsT>,
+        subscribers: impl IntoSubscribers<StateT, FwdPropsT>,
+    ) -> Result<RunAgentResult<StateT>, ToolLoopError>
+    where
+        A: Agent<StateT, FwdPropsT> + Sync,
+        FwdPropsT: FwdProps,
+    {
+        let subscribers = subscribers.into_subscribers();
+
+        for step in 0..self.max_steps {
+            let step_name = format!("tool_loop_step_{step}");
+            let input = snapshot_input(&params);
+            self.notify_step(&subscribers, &step_name, true, &params, &input)
+                .await;
+
+            let result = agent.run_agent(&params, subscribers.clone()).await?;
+            let pending = pending_tool_calls(&result.new_messages);
+
+            params.messages.extend(result.new_messages.clone());
+            params.state = result.new_state.clone();
+
+            self.notify_step(&subscribers, &step_name, false, &params, &input)
+                .await;
+
+            if pending.is_empty() {
+                return Ok(result);
+            }
+
+            match self.execute_tool_calls(&pending).await {
+                Ok(tool_messages) => {
+                    params
+                        .messages
+                        .extend(tool_messages.into_iter().map(Message::from));
+                }
+                Err(err) => {
+                    self.notify_run_error(&subscribers, &err.to_string(), &params, &input)
+                        .await;
+                    return Err(err);
+                }
+            }
+        }
+
+        Err(ToolLoopError::MaxStepsExceeded {
+            max_steps: self.max_steps,
+        })
+    }
+
+    /// Executes every pending tool call concurrently -- calls within one assistant turn are
+    /// independent of each other -- bounded to the available CPU parallelism so a turn with many
+    /// tool calls doesn't spawn unbounded work, and returns one [`ToolMessage`] per call in the
+    /// original order so callers don't have to re-sort by `tool_call_id`.
+    async fn execute_tool_calls(
+        &self,
+        tool_calls: &[ToolCall],
+    ) -> Result<Vec<ToolMessage>, ToolLoopError> {
+        let concurrency = available_parallelism().map(|n| n.get()).unwrap_or(1);
+
+        stream::iter(tool_calls)
+            .map(|tool_call| self.execute_tool_call(tool_call))
+            .buffered(concurrency)
+            .try_collect()
+            .await
+    }
+
+    async fn execute_tool_call(&self, tool_call: &ToolCall) -> Result<ToolMessage, ToolLoopError> {
+        let (tool, handler) = self
+            .tools
+            .get(&tool_call.function.name)
+            .ok_or_else(|| ToolLoopError::UnknownTool(tool_call.function.name.clone()))?;
+
+        let args = tool_call
+            .function
+            .arguments_value()
+            .map_err(|source| ToolLoopError::ArgsJson {
+                tool_call_id: tool_call.id.clone(),
+                source,
+            })?;
+
+        crate::core::validate_against_schema(&tool.parameters, &args).map_err(|reason| {
+            ToolLoopError::SchemaValidation {
+                tool: tool.name.clone(),
+                reason: reason.to_string(),
+            }
+        })?;
+
+        let output = handler(args)
+            .await
+            .map_err(|source| ToolLoopError::ToolHandler {
+                tool: tool.name.clone(),
+                source,
+            })?;
+
+        let content = match &output {
+            JsonValue::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+
+        Ok(ToolMessage::new(
+            MessageId::random(),
+            MessageContent::text(content),
+            tool_call.id.clone(),
+        ))
+    }
+
+    async fn notify_step<FwdPropsT>(
+        &self,
+        subscribers: &Subscribers<StateT, FwdPropsT>,
+        step_name: &str,
+        started: bool,
+        params: &RunAgentParams<StateT, FwdPropsT>,
+        input: &RunAgentInput<StateT, FwdPropsT>,
+    ) where
+        FwdPropsT: FwdProps,
+    {
+        let notify_params = AgentSubscriberParams {
+            messages: &params.messages,
+            state: &params.state,
+            input,
+        };
+
+        for subscriber in subscribers {
+            let base = BaseEvent {
+                timestamp: None,
+                raw_event: None,
+            };
+            let _ = if started {
+                subscriber
+                    .on_step_started_event(
+                        &StepStartedEvent {
+                            base,
+                            step_name: step_name.to_string(),
+                        },
+                        notify_params_clone(&notify_params),
+                    )
+                    .await
+            } else {
+                subscriber
+                    .on_step_finished_event(
+                        &StepFinishedEvent {
+                            base,
+                            step_name: step_name.to_string(),
+                        },
+                        notify_params_clone(&notify_params),
+                    )
+                    .await
+            };
+        }
+    }
+
+    async fn notify_run_error<FwdPropsT>(
+        &self,
+        subscribers: &Subscribers<StateT, FwdPropsT>,
+        message: &str,
+        params: &RunAgentParams<StateT, FwdPropsT>,
+        input: &RunAgentInput<StateT, FwdPropsT>,
+    ) where
+        FwdPropsT: FwdProps,
+    {
+        let notify_params = AgentSubscriberParams {
+            messages: &params.messages,
+            state: &params.state,
+            input,
+        };
+
+        let event = RunErrorEvent {
+            base: BaseEvent {
+                timestamp: None,
+                raw_event: None,
+            },
+            message: message.to_string(),
+            code: None,
+        };
+
+        for subscriber in subscribers {
+            let _ = subscriber
+                .on_run_error_event(&event, notify_params_clone(&notify_params))
+                .await;
+        }
+    }
+}
+
+fn notify_params_clone<'a, StateT: AgentState, FwdPropsT: FwdProps>(
+    params: &AgentSubscriberParams<'a, StateT, FwdPropsT>,
+) -> AgentSubscriberParams<'a, StateT, FwdPropsT> {
+    AgentSubscriberParams {
+        messages: params.messages,
+        state: params.state,
+        input: params.input,
+    }
+}
+
+fn snapshot_input<StateT: AgentState, FwdPropsT: FwdProps>(
+    params: &RunAgentParams<StateT, FwdPropsT>,
+) -> RunAgentInput<StateT, FwdPropsT> {
+    RunAgentInput {
+        thread_id: ThreadId::random(),
+        run_id: params.run_id.clone().unwrap_or_else(RunId::random),
+        state: params.state.clone(),
+        messages: params.messages.clone(),
+        tools: params.tools.clone(),
+        context: params.context.clone(),
+        forwarded_props: params.forwarded_props.clone(),
+    }
+}
+
+/// Every tool call attached to a freshly produced `Assistant` message: nothing has replied to
+/// these yet, since `run_agent`'s event handler only attaches `tool_calls` and never itself
+/// synthesizes a `tool` result message.
+fn pending_tool_calls(new_messages: &[Message]) -> Vec<ToolCall> {
+    new_messages
+        .iter()
+        .filter_map(|m| match m {
+            Message::Assistant {
+                tool_calls: Some(calls),
+                ..
+            } => Some(calls.clone()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+pub struct ToolLoopBuilder<StateT: AgentState = JsonValue> {
+    tools: HashMap<String, (Tool, ToolHandler)>,
+    max_steps: u32,
+    _state: std::marker::PhantomData<StateT>,
+}
+
+impl<StateT: AgentState> ToolLoopBuilder<StateT> {
+    pub fn new() -> Self {
+        Self {
+            tools: HashMap::new(),
+            max_steps: 10,
+            _state: std::marker::PhantomData,
+        }
+    }
+
+    /// Registers a tool definition and its async handler, keyed by `tool.name`.
+    pub fn register(
+        mut self,
+        tool: Tool,
+        handler: impl Fn(JsonValue) -> BoxFuture<'static, Result<JsonValue, ToolHandlerError>>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.tools
+            .insert(tool.name.clone(), (tool, Box::new(handler)));
+        self
+    }
+
+    /// Caps how many rounds of agent-run + tool-execution the loop will drive before giving up
+    /// with [`ToolLoopError::MaxStepsExceeded`]. Defaults to 10.
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn build(self) -> ToolLoop<StateT> {
+        ToolLoop {
+            tools: self.tools,
+            max_steps: self.max_steps,
+            _state: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<StateT: AgentState> Default for ToolLoopBuilder<StateT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use bytes::Bytes;
+use futures::Stream;
+use reqwest::StatusCode;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+
+use crate::error::AgUiClientError;
+
+/// The status line and headers of an HTTP/1.1 response read over a Unix domain socket, before
+/// the body is streamed.
+pub struct UdsResponseHead {
+    pub status: StatusCode,
+    pub headers: HashMap<String, String>,
+}
+
+impl UdsResponseHead {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+}
+
+/// Sends a single HTTP/1.1 request over a Unix domain socket and returns the parsed response
+/// head plus a stream of the remaining body bytes.
+///
+/// This hand-rolls just enough of HTTP/1.1 to talk to a locally-hosted AG-UI agent: a request
+/// line, headers, and a body read until the connection closes. Agent runtimes behind a UDS are
+/// expected to close the connection once a run finishes (there's no pooled connection to
+/// preserve across runs), so reading to EOF is sufficient without implementing chunked
+/// transfer-encoding or `Content-Length` framing on the response side.
+pub async fn send_request(
+    socket_path: &Path,
+    request_target: &str,
+    host: &str,
+    headers: &[(String, String)],
+    body: Vec<u8>,
+) -> Result<
+    (
+        UdsResponseHead,
+        impl Stream<Item = Result<Bytes, AgUiClientError>> + use<>,
+    ),
+    AgUiClientError,
+> {
+    let mut conn = UnixStream::connect(socket_path)
+        .await
+        .map_err(|e| transport_err(format!("connecting to '{}': {e}", socket_path.display())))?;
+
+    let mut request = format!("POST {request_target} HTTP/1.1\r\nHost: {host}\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    request.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", body.len()));
+
+    conn.write_all(request.as_bytes())
+        .await
+        .map_err(|e| transport_err(format!("writing request headers: {e}")))?;
+    conn.write_all(&body)
+        .await
+        .map_err(|e| transport_err(format!("writing request body: {e}")))?;
+
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = conn
+            .read(&mut chunk)
+            .await
+            .map_err(|e| transport_err(format!("reading response: {e}")))?;
+        if n == 0 {
+            return Err(transport_err(
+                "connection closed before response headers completed".to_string(),
+            ));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = find_subslice(&buf, b"\r\n\r\n") {
+            break pos;
+        }
+    };
+
+    let head_text = std::str::from_utf8(&buf[..header_end])
+        .map_err(|e| transport_err(format!("response head is not valid UTF-8: {e}")))?;
+    let head = parse_response_head(head_text)?;
+    let leftover_body = buf[header_end + 4..].to_vec();
+
+    let body_stream = futures::stream::unfold(
+        (conn, Some(leftover_body)),
+        |(mut conn, pending)| async move {
+            if let Some(bytes) = pending
+                && !bytes.is_empty()
+            {
+                return Some((Ok(Bytes::from(bytes)), (conn, None)));
+            }
+            let mut chunk = [0u8; 8192];
+            match conn.read(&mut chunk).await {
+                Ok(0) => None,
+                Ok(n) => Some((Ok(Bytes::copy_from_slice(&chunk[..n])), (conn, None))),
+                Err(e) => Some((
+                    Err(transport_err(format!("reading response body: {e}"))),
+                    (conn, None),
+                )),
+            }
+        },
+    );
+
+    Ok((head, body_stream))
+}
+
+fn parse_response_head(text: &str) -> Result<UdsResponseHead, AgUiClientError> {
+    let mut lines = text.split("\r\n");
+    let status_line = lines
+        .next()
+        .ok_or_else(|| transport_err("empty response".to_string()))?;
+    let status_code = status_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| transport_err(format!("malformed status line: '{status_line}'")))?;
+    let status = StatusCode::from_bytes(status_code.as_bytes())
+        .map_err(|e| transport_err(format!("invalid status code '{status_code}': {e}")))?;
+
+    let mut headers = HashMap::new();
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    Ok(UdsResponseHead { status, headers })
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn transport_err(message: String) -> AgUiClientError {
+    AgUiClientError::Transport { message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status_line_and_headers() {
+        let head = parse_response_head(
+            "HTTP/1.1 429 Too Many Requests\r\nContent-Type: text/event-stream\r\nRetry-After: 5",
+        )
+        .unwrap();
+        assert_eq!(head.status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(head.header("content-type"), Some("text/event-stream"));
+        assert_eq!(head.header("Retry-After"), Some("5"));
+    }
+
+    #[test]
+    fn find_subslice_locates_header_terminator() {
+        let haystack = b"HTTP/1.1 200 OK\r\n\r\ndata: hi\n\n";
+        assert_eq!(find_subslice(haystack, b"\r\n\r\n"), Some(15));
+    }
+}
@@ -0,0 +1,178 @@
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::core::event::Event;
+use crate::core::AgentState;
+
+/// Which wire format an [`EventReader`]/[`EventWriter`] frames `Event`s in over a raw byte
+/// stream (stdio pipes, sockets, ...) where SSE/HTTP isn't available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireFormat {
+    /// One compact `Event` JSON object per line (newline-delimited JSON).
+    Ndjson,
+    /// LSP-style `Content-Length: N\r\n\r\n<body>` framing: a header section terminated by a
+    /// blank line, followed by exactly `N` bytes of JSON body.
+    ContentLength,
+}
+
+/// Errors reading or writing `Event`s over a [`WireFormat`]-framed byte stream.
+#[derive(Debug, thiserror::Error)]
+pub enum TransportError {
+    /// The underlying byte stream failed, including ending unexpectedly mid-frame.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A frame's body didn't parse as the tagged `Event` JSON shape.
+    #[error("failed to decode event JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A `Content-Length` header was missing, duplicated, or not a valid number.
+    #[error("malformed Content-Length header: {0:?}")]
+    MalformedHeader(String),
+
+    /// An ndjson line wasn't empty but also didn't parse as a type-tagged event.
+    #[error("line does not parse as a type-tagged AG-UI event: {0:?}")]
+    InvalidLine(String),
+}
+
+/// Reads `Event<StateT>` values off an arbitrary [`AsyncBufRead`] byte stream, framed per
+/// [`WireFormat`].
+///
+/// Both formats read through `tokio`'s buffered helpers (`read_line`, `read_exact`), which
+/// already loop internally until a full line/exact byte count is available, so a frame split
+/// across several underlying reads (short reads, a body straddling two TCP segments, ...) is
+/// handled transparently -- callers never see a partial frame.
+pub struct EventReader<R> {
+    inner: R,
+    format: WireFormat,
+}
+
+impl<R: AsyncBufRead + Unpin> EventReader<R> {
+    /// Wraps `inner`, framing reads according to `format`.
+    pub fn new(inner: R, format: WireFormat) -> Self {
+        Self { inner, format }
+    }
+
+    /// Reads the next event, or `Ok(None)` on a clean end of stream between frames.
+    pub async fn read_event<StateT: AgentState>(
+        &mut self,
+    ) -> Result<Option<Event<StateT>>, TransportError> {
+        match self.format {
+            WireFormat::Ndjson => self.read_ndjson().await,
+            WireFormat::ContentLength => self.read_content_length().await,
+        }
+    }
+
+    async fn read_ndjson<StateT: AgentState>(
+        &mut self,
+    ) -> Result<Option<Event<StateT>>, TransportError> {
+        loop {
+            let mut line = String::new();
+            let n = self.inner.read_line(&mut line).await?;
+            if n == 0 {
+                return Ok(None);
+            }
+
+            let trimmed = line.trim_end_matches(['\r', '\n']);
+            if trimmed.is_empty() {
+                // Tolerate a blank line between records (e.g. a trailing newline at EOF).
+                continue;
+            }
+
+            return serde_json::from_str(trimmed)
+                .map(Some)
+                .map_err(|_| TransportError::InvalidLine(trimmed.to_string()));
+        }
+    }
+
+    async fn read_content_length<StateT: AgentState>(
+        &mut self,
+    ) -> Result<Option<Event<StateT>>, TransportError> {
+        let mut content_length: Option<usize> = None;
+
+        loop {
+            let mut line = String::new();
+            let n = self.inner.read_line(&mut line).await?;
+            if n == 0 {
+                if content_length.is_none() {
+                    return Ok(None);
+                }
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            }
+
+            let trimmed = line.trim_end_matches(['\r', '\n']);
+            if trimmed.is_empty() {
+                // Blank line: end of the header section.
+                break;
+            }
+
+            if let Some(value) = trimmed
+                .split_once(':')
+                .filter(|(name, _)| name.eq_ignore_ascii_case("Content-Length"))
+                .map(|(_, value)| value.trim())
+            {
+                if content_length.is_some() {
+                    return Err(TransportError::MalformedHeader(format!(
+                        "duplicate Content-Length header: {trimmed}"
+                    )));
+                }
+                content_length = Some(
+                    value
+                        .parse()
+                        .map_err(|_| TransportError::MalformedHeader(trimmed.to_string()))?,
+                );
+            }
+        }
+
+        let declared = content_length.ok_or_else(|| {
+            TransportError::MalformedHeader("missing Content-Length header".to_string())
+        })?;
+
+        let mut body = vec![0u8; declared];
+        self.inner.read_exact(&mut body).await?;
+
+        Ok(Some(serde_json::from_slice(&body)?))
+    }
+}
+
+/// Writes `Event<StateT>` values to an arbitrary [`AsyncWrite`] byte stream, framed per
+/// [`WireFormat`].
+pub struct EventWriter<W> {
+    inner: W,
+    format: WireFormat,
+}
+
+impl<W: AsyncWrite + Unpin> EventWriter<W> {
+    /// Wraps `inner`, framing writes according to `format`.
+    pub fn new(inner: W, format: WireFormat) -> Self {
+        Self { inner, format }
+    }
+
+    /// Serializes and writes a single event, flushing the underlying stream.
+    pub async fn write_event<StateT: AgentState>(
+        &mut self,
+        event: &Event<StateT>,
+    ) -> Result<(), TransportError> {
+        let body = serde_json::to_vec(event)?;
+
+        match self.format {
+            WireFormat::Ndjson => {
+                self.inner.write_all(&body).await?;
+                self.inner.write_all(b"\n").await?;
+            }
+            WireFormat::ContentLength => {
+                let header = format!("Content-Length: {}\r\n\r\n", body.len());
+                self.inner.write_all(header.as_bytes()).await?;
+                self.inner.write_all(&body).await?;
+            }
+        }
+
+        self.inner.flush().await?;
+        Ok(())
+    }
+}
+
+/// Blanket helper so a plain [`AsyncRead`] (e.g. a pipe with no buffering of its own) can be
+/// wrapped in a [`tokio::io::BufReader`] before use with [`EventReader`].
+pub fn buffered<R: AsyncRead + Unpin>(inner: R) -> tokio::io::BufReader<R> {
+    tokio::io::BufReader::new(inner)
+}
@@ -0,0 +1,391 @@
+use futures::stream::SplitSink;
+use futures::{SinkExt, Stream, StreamExt};
+use std::collections::HashSet;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+use uuid::Uuid;
+
+use crate::Agent;
+use crate::agent::{AgentError, RunAgentParams, RunAgentResult};
+use crate::core::event::Event;
+use crate::core::types::{AgentId, MessageId, RunAgentInput, RunId, ThreadId};
+use crate::core::{AgentState, FwdProps, base64_decode};
+use crate::error::AgUiClientError;
+use crate::event_handler::EventHandler;
+use crate::sse::SseEvent;
+use crate::stream::EventStream;
+use crate::subscriber::IntoSubscribers;
+use reqwest::Url;
+
+/// Per-connection identity and concurrency bound for a WebSocket-backed AG-UI connection.
+///
+/// Mirrors the knobs a server-side connection manager would track for a socket that may be
+/// asked to drive several concurrent runs.
+#[derive(Debug, Clone)]
+pub struct ClientConnConfig {
+    /// A locally-generated identifier for this connection, useful for correlating logs.
+    pub connection_id: Uuid,
+    /// The maximum number of concurrent runs configured for this connection, exposed through
+    /// [`WsConnection::max_in_flight_runs`]; `WsConnection` and `WsAgent` do not enforce it.
+    pub max_in_flight_runs: usize,
+}
+
+impl Default for ClientConnConfig {
+    fn default() -> Self {
+        Self {
+            connection_id: Uuid::new_v4(),
+            max_in_flight_runs: 1,
+        }
+    }
+}
+
+/// A WebSocket transport that yields the same [`SseEvent`] stream as [`crate::sse::SseResponseExt`],
+/// so downstream consumers can stay transport-agnostic.
+///
+/// Unlike SSE, the socket is bidirectional: [`WsConnection::send_input`] lets the client push a
+/// [`RunAgentInput`] frame to the agent without issuing a fresh HTTP request.
+pub struct WsConnection {
+    inner: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    config: ClientConnConfig,
+}
+
+impl WsConnection {
+    /// Connect to `url` (a `ws://` or `wss://` endpoint) using a default [`ClientConnConfig`].
+    pub async fn connect(url: &str) -> Result<Self, AgUiClientError> {
+        Self::connect_with_config(url, ClientConnConfig::default()).await
+    }
+
+    /// Connect to `url` with an explicit [`ClientConnConfig`].
+    pub async fn connect_with_config(
+        url: &str,
+        config: ClientConnConfig,
+    ) -> Result<Self, AgUiClientError> {
+        let (inner, _response) =
+            connect_async(url)
+                .await
+                .map_err(|e| AgUiClientError::Transport {
+                    message: format!("WebSocket connect to '{url}' failed: {e}"),
+                })?;
+        Ok(Self { inner, config })
+    }
+
+    pub fn connection_id(&self) -> Uuid {
+        self.config.connection_id
+    }
+
+    pub fn max_in_flight_runs(&self) -> usize {
+        self.config.max_in_flight_runs
+    }
+
+    /// Push a `RunAgentInput` frame to the agent over the outbound side of the socket.
+    pub async fn send_input<StateT, FwdPropsT>(
+        &mut self,
+        input: &RunAgentInput<StateT, FwdPropsT>,
+    ) -> Result<(), AgUiClientError>
+    where
+        StateT: AgentState,
+        FwdPropsT: FwdProps,
+    {
+        let text = serde_json::to_string(input)?;
+        self.inner
+            .send(WsMessage::Text(text.into()))
+            .await
+            .map_err(|e| AgUiClientError::Transport {
+                message: format!("WebSocket send failed: {e}"),
+            })
+    }
+
+    /// Consume the connection, yielding a stream of [`SseEvent`]s identical in shape to the ones
+    /// produced by the SSE transport.
+    ///
+    /// Each text frame is treated as one complete event payload (no `\n\n` buffering is needed,
+    /// unlike SSE). Binary frames are accepted when they carry UTF-8 JSON directly, or base64
+    /// wrapped UTF-8 JSON; anything else surfaces as an error item. Ping/pong keepalive frames are
+    /// dropped transparently rather than surfaced as an event (tungstenite already answers a
+    /// `Ping` with a `Pong` on its own); a `Close` frame is likewise dropped, letting the
+    /// underlying socket's natural end-of-stream follow it so the run finalizes normally instead
+    /// of erroring.
+    pub fn into_event_stream(self) -> impl Stream<Item = Result<SseEvent, AgUiClientError>> {
+        self.inner
+            .filter_map(|msg| futures::future::ready(translate_ws_message(msg)))
+    }
+
+    /// Splits the connection into an independent write half and a read-only [`SseEvent`] stream.
+    ///
+    /// Unlike [`Self::into_event_stream`], which gives up the ability to write once it starts
+    /// reading, this lets a caller interleave sending frames (e.g. a subscriber's
+    /// [`crate::agent::AgentStateMutation`] pushed back upstream) with consuming events, which is
+    /// what makes the connection genuinely full-duplex. See `WsAgent::run_agent`.
+    pub fn split(self) -> (WsSink, impl Stream<Item = Result<SseEvent, AgUiClientError>>) {
+        let (sink, stream) = self.inner.split();
+        (
+            WsSink(sink),
+            stream.filter_map(|msg| futures::future::ready(translate_ws_message(msg))),
+        )
+    }
+}
+
+/// The write half of a [`WsConnection`] split via [`WsConnection::split`].
+pub struct WsSink(SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, WsMessage>);
+
+impl WsSink {
+    /// Serialize `value` as JSON and push it upstream as a single text frame.
+    pub async fn send_json<T: serde::Serialize>(&mut self, value: &T) -> Result<(), AgUiClientError> {
+        let text = serde_json::to_string(value)?;
+        self.0
+            .send(WsMessage::Text(text.into()))
+            .await
+            .map_err(|e| AgUiClientError::Transport {
+                message: format!("WebSocket send failed: {e}"),
+            })
+    }
+}
+
+/// Translates one inbound WebSocket frame into an [`SseEvent`] item for the event stream, or
+/// `None` to drop it transparently (keepalive ping/pong, and the close frame itself -- the
+/// underlying socket's own end-of-stream follows right after it).
+fn translate_ws_message(
+    msg_result: Result<WsMessage, tokio_tungstenite::tungstenite::Error>,
+) -> Option<Result<SseEvent, AgUiClientError>> {
+    match msg_result {
+        Ok(WsMessage::Text(text)) => Some(Ok(SseEvent {
+            event: None,
+            id: None,
+            data: text.to_string(),
+            retry: None,
+        })),
+        Ok(WsMessage::Binary(bytes)) => Some(decode_binary_frame(&bytes)),
+        Ok(WsMessage::Close(_)) | Ok(WsMessage::Ping(_)) | Ok(WsMessage::Pong(_)) | Ok(WsMessage::Frame(_)) => {
+            None
+        }
+        Err(e) => Some(Err(AgUiClientError::Transport {
+            message: format!("WebSocket transport error: {e}"),
+        })),
+    }
+}
+
+/// Decode a binary WebSocket frame into an [`SseEvent`], accepting either raw UTF-8 JSON or
+/// base64-wrapped UTF-8 JSON.
+fn decode_binary_frame(bytes: &[u8]) -> Result<SseEvent, AgUiClientError> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return Ok(SseEvent {
+            event: None,
+            id: None,
+            data: text.to_string(),
+            retry: None,
+        });
+    }
+
+    let decoded = base64_decode(bytes).ok_or_else(|| AgUiClientError::SseParse {
+        message: "binary frame is neither valid UTF-8 nor base64-encoded UTF-8".to_string(),
+    })?;
+    let text = String::from_utf8(decoded).map_err(|e| AgUiClientError::SseParse {
+        message: format!("base64-decoded binary frame is not valid UTF-8: {e}"),
+    })?;
+    Ok(SseEvent {
+        event: None,
+        id: None,
+        data: text,
+        retry: None,
+    })
+}
+
+/// An agent that communicates over a persistent, bidirectional WebSocket connection instead of
+/// SSE, built on [`WsConnection`].
+///
+/// Shares `RunAgentParams`/`run_agent`/`AgentSubscriber` with [`crate::HttpAgent`] -- the only
+/// difference is the transport and that [`Agent::run_agent`] is overridden here to take advantage
+/// of the socket's full duplex: a subscriber's [`crate::agent::AgentStateMutation`] is serialized and pushed
+/// back upstream as soon as it's produced, which [`Agent::run`]'s one-way `EventStream` alone has
+/// no way to express.
+pub struct WsAgent {
+    url: Url,
+    agent_id: Option<AgentId>,
+    conn_config: ClientConnConfig,
+}
+
+impl WsAgent {
+    pub fn builder() -> WsAgentBuilder {
+        WsAgentBuilder::new()
+    }
+}
+
+pub struct WsAgentBuilder {
+    url: Option<Url>,
+    agent_id: Option<AgentId>,
+    conn_config: ClientConnConfig,
+}
+
+impl WsAgentBuilder {
+    pub fn new() -> Self {
+        Self {
+            url: None,
+            agent_id: None,
+            conn_config: ClientConnConfig::default(),
+        }
+    }
+
+    /// Set the `ws://`/`wss://` URL from a parsed [`Url`].
+    pub fn with_url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Set the `ws://`/`wss://` URL from a string, returning `Result` for validation.
+    pub fn with_url_str(mut self, url: &str) -> Result<Self, AgentError> {
+        let parsed_url = Url::parse(url).map_err(|e| AgentError::Config {
+            message: format!("Invalid URL '{url}': {e}"),
+        })?;
+        self.url = Some(parsed_url);
+        Ok(self)
+    }
+
+    pub fn with_agent_id(mut self, agent_id: AgentId) -> Self {
+        self.agent_id = Some(agent_id);
+        self
+    }
+
+    /// Override the connection's identity/concurrency knobs; see [`ClientConnConfig`].
+    pub fn with_connection_config(mut self, config: ClientConnConfig) -> Self {
+        self.conn_config = config;
+        self
+    }
+
+    pub fn build(self) -> Result<WsAgent, AgentError> {
+        let url = self.url.ok_or(AgentError::Config {
+            message: "Base URL is required".to_string(),
+        })?;
+        if !["ws", "wss"].contains(&url.scheme()) {
+            return Err(AgentError::Config {
+                message: format!("Unsupported URL scheme: {}", url.scheme()),
+            });
+        }
+
+        Ok(WsAgent {
+            url,
+            agent_id: self.agent_id,
+            conn_config: self.conn_config,
+        })
+    }
+}
+
+impl Default for WsAgentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateT, FwdPropsT> Agent<StateT, FwdPropsT> for WsAgent
+where
+    StateT: AgentState,
+    FwdPropsT: FwdProps,
+{
+    async fn run(
+        &self,
+        input: &RunAgentInput<StateT, FwdPropsT>,
+    ) -> Result<EventStream<'async_trait, StateT>, AgentError> {
+        let mut conn =
+            WsConnection::connect_with_config(self.url.as_str(), self.conn_config.clone()).await?;
+        conn.send_input(input).await?;
+
+        let stream = conn
+            .into_event_stream()
+            .map(|result| match result {
+                Ok(event) => decode_ws_event::<StateT>(&event.data),
+                Err(err) => Err(err),
+            })
+            .boxed();
+        Ok(stream)
+    }
+
+    /// Runs the agent over a single split connection so a subscriber's [`crate::agent::AgentStateMutation`] can
+    /// be pushed back upstream the moment it's produced, which the default `run_agent` (built
+    /// around the one-way [`Agent::run`]) has no way to do.
+    async fn run_agent(
+        &self,
+        params: &RunAgentParams<StateT, FwdPropsT>,
+        subscribers: impl IntoSubscribers<StateT, FwdPropsT>,
+    ) -> Result<RunAgentResult<StateT>, AgentError> {
+        let input = RunAgentInput {
+            thread_id: ThreadId::random(),
+            run_id: params.run_id.clone().unwrap_or_else(RunId::random),
+            state: params.state.clone(),
+            messages: params.messages.clone(),
+            tools: params.tools.clone(),
+            context: params.context.clone(),
+            forwarded_props: params.forwarded_props.clone(),
+        };
+        let current_message_ids: HashSet<&MessageId> =
+            params.messages.iter().map(|m| m.id()).collect();
+
+        let subscribers = subscribers.into_subscribers();
+        let mut event_handler = EventHandler::new(
+            params.messages.clone(),
+            params.state.clone(),
+            &input,
+            subscribers,
+        );
+
+        let mut conn =
+            WsConnection::connect_with_config(self.url.as_str(), self.conn_config.clone()).await?;
+        conn.send_input(&input).await?;
+        let (mut sink, stream) = conn.split();
+        let mut stream = Box::pin(stream);
+
+        while let Some(event_result) = stream.next().await {
+            let event = match event_result.and_then(|e| decode_ws_event::<StateT>(&e.data)) {
+                Ok(event) => event,
+                Err(e) => {
+                    event_handler.on_error(&e).await?;
+                    return Err(e);
+                }
+            };
+
+            let mutation = event_handler.handle_event(&event).await?;
+            if mutation.messages.is_some() || mutation.state.is_some() {
+                sink.send_json(&mutation).await?;
+            }
+            event_handler.apply_mutation(mutation).await?;
+        }
+
+        event_handler.on_finalize().await?;
+        let new_messages = event_handler
+            .messages
+            .iter()
+            .filter(|m| !current_message_ids.contains(&m.id()))
+            .cloned()
+            .collect();
+
+        Ok(RunAgentResult {
+            result: event_handler.result,
+            new_messages,
+            new_state: event_handler.state,
+        })
+    }
+
+    fn agent_id(&self) -> Option<&AgentId> {
+        self.agent_id.as_ref()
+    }
+}
+
+/// Decode a single WebSocket text/binary frame's payload into an [`Event`].
+///
+/// Unlike the SSE transport, the WebSocket transport carries one complete JSON `Event` per frame
+/// with no multi-encoding negotiation, so no `EncodingType`/base64 unwrapping is needed here --
+/// [`decode_binary_frame`] already resolved a binary frame down to JSON text before this runs.
+fn decode_ws_event<StateT: AgentState>(data: &str) -> Result<Event<StateT>, AgentError> {
+    Ok(serde_json::from_str(data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_frame_with_raw_utf8_decodes_directly() {
+        let event = decode_binary_frame(br#"{"type":"RUN_STARTED"}"#).unwrap();
+        assert_eq!(event.data, r#"{"type":"RUN_STARTED"}"#);
+    }
+}
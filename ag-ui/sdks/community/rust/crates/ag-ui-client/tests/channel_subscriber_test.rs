@@ -0,0 +1,74 @@
+use futures::StreamExt;
+
+use ag_ui_client::agent::{Agent, RunAgentParams};
+use ag_ui_client::channel_subscriber::{ChannelSubscriber, FanOutItem, FanOutSubscriber};
+use ag_ui_client::core::types::{MessageId, RunId, ThreadId};
+use ag_ui_client::mock::MockAgent;
+
+mod common;
+use common::text_message_script;
+
+#[tokio::test]
+async fn unbounded_channel_subscriber_yields_every_event_as_a_stream() {
+    let script = text_message_script(MessageId::random(), ThreadId::random(), RunId::random());
+    let script_len = script.len();
+
+    let agent = MockAgent::builder().script(script).build();
+    let (subscriber, mut stream) = ChannelSubscriber::unbounded();
+    let params = RunAgentParams::new().user("hello");
+
+    agent.run_agent(&params, (subscriber,)).await.unwrap();
+
+    let mut observed = 0;
+    while stream.next().await.is_some() {
+        observed += 1;
+    }
+    assert_eq!(observed, script_len);
+}
+
+#[tokio::test]
+async fn fan_out_subscriber_delivers_every_event_to_each_independent_subscription() {
+    let script = text_message_script(MessageId::random(), ThreadId::random(), RunId::random());
+    let script_len = script.len();
+
+    let agent = MockAgent::builder().script(script).build();
+    let (subscriber, mut first) = FanOutSubscriber::new(16);
+    let mut second = subscriber.subscribe();
+    let params = RunAgentParams::new().user("hello");
+
+    // Dropping `subscriber` (moved into the run) after the run completes closes the broadcast
+    // channel, so both streams end on their own once drained.
+    agent.run_agent(&params, (subscriber,)).await.unwrap();
+
+    for stream in [&mut first, &mut second] {
+        let mut observed = 0;
+        while stream.next().await.is_some() {
+            observed += 1;
+        }
+        assert_eq!(observed, script_len);
+    }
+}
+
+#[tokio::test]
+async fn fan_out_subscriber_surfaces_a_lagged_receiver_as_a_stream_item() {
+    let script = text_message_script(MessageId::random(), ThreadId::random(), RunId::random());
+    let agent = MockAgent::builder().script(script).build();
+    let (subscriber, mut stream) = FanOutSubscriber::new(1);
+    let params = RunAgentParams::new().user("hello");
+
+    // Run to completion without draining `stream`, so the receiver falls behind the
+    // single-slot buffer and its next read must observe a `Lagged` item instead of silently
+    // missing the events it couldn't keep up with.
+    agent.run_agent(&params, (subscriber,)).await.unwrap();
+
+    let mut saw_lagged = false;
+    while let Some(item) = stream.next().await {
+        if matches!(item, FanOutItem::Lagged { .. }) {
+            saw_lagged = true;
+        }
+    }
+    assert!(
+        saw_lagged,
+        "expected the lagging receiver to surface a Lagged item"
+    );
+}
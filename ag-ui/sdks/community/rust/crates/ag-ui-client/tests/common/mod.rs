@@ -0,0 +1,50 @@
+//! Shared fixtures for this crate's integration tests, so each test file doesn't re-derive the
+//! same scripted `Event` sequences. Not every test file needs every helper here -- `#[allow(dead_code)]`
+//! on each one keeps an unused helper from failing a particular file's build under `-D warnings`.
+
+use ag_ui_client::core::event::{
+    BaseEvent, Event, RunFinishedEvent, RunStartedEvent, TextMessageContentEvent,
+    TextMessageEndEvent, TextMessageStartEvent,
+};
+use ag_ui_client::core::types::{MessageId, Role, RunId, ThreadId};
+
+/// An empty [`BaseEvent`], since none of these tests care about `timestamp`/`raw_event`.
+#[allow(dead_code)]
+pub fn base_event() -> BaseEvent {
+    BaseEvent {
+        timestamp: None,
+        raw_event: None,
+    }
+}
+
+/// A minimal complete run: `RunStarted` -> one assistant text message -> `RunFinished`.
+#[allow(dead_code)]
+pub fn text_message_script(message_id: MessageId, thread_id: ThreadId, run_id: RunId) -> Vec<Event> {
+    vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            role: Role::Assistant,
+        }),
+        Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            delta: "hi".to_string(),
+        }),
+        Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id,
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id,
+            run_id,
+            result: None,
+        }),
+    ]
+}
@@ -0,0 +1,99 @@
+use ag_ui_client::core::event::{
+    Event, RunFinishedEvent, RunStartedEvent, TextMessageContentEvent, TextMessageEndEvent,
+    TextMessageStartEvent,
+};
+use ag_ui_client::core::types::{MessageId, RunId, ThreadId};
+use ag_ui_client::envelope::{Envelope, EnvelopeError, EnvelopeHeader};
+
+mod common;
+use common::base_event;
+
+fn sample_run() -> (ThreadId, RunId, Vec<Event>) {
+    let thread_id = ThreadId::random();
+    let run_id = RunId::random();
+    let message_id = MessageId::random();
+
+    let events = vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent::new(message_id.clone())),
+        Event::TextMessageContent(
+            TextMessageContentEvent::new(message_id.clone(), "hi".to_string()).unwrap(),
+        ),
+        Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id,
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+            result: None,
+        }),
+    ];
+
+    (thread_id, run_id, events)
+}
+
+#[test]
+fn to_writer_then_from_reader_round_trips_an_envelope() {
+    let (thread_id, run_id, events) = sample_run();
+    let envelope = Envelope::new(
+        EnvelopeHeader {
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        },
+        events.clone(),
+    );
+
+    let mut buf = Vec::new();
+    envelope.to_writer(&mut buf).unwrap();
+
+    let decoded: Envelope<serde_json::Value> = Envelope::from_reader(&buf[..]).unwrap();
+    assert_eq!(decoded.header.thread_id, thread_id);
+    assert_eq!(decoded.header.run_id, run_id);
+    assert_eq!(decoded.events, events);
+}
+
+#[test]
+fn from_reader_rejects_a_malformed_body_line() {
+    let input = b"{\"threadId\":\"t\",\"runId\":\"r\"}\nnot an event\n";
+    let err = Envelope::<serde_json::Value>::from_reader(&input[..]).unwrap_err();
+    assert!(matches!(err, EnvelopeError::Event { .. }));
+}
+
+#[tokio::test]
+async fn from_stream_drains_between_run_started_and_run_finished() {
+    let (thread_id, run_id, events) = sample_run();
+    let stream = Box::pin(futures::stream::iter(events.clone()));
+
+    let envelope = Envelope::from_stream(stream).await.unwrap();
+    assert_eq!(envelope.header.thread_id, thread_id);
+    assert_eq!(envelope.header.run_id, run_id);
+    assert_eq!(envelope.events, events);
+}
+
+#[tokio::test]
+async fn from_stream_errors_when_no_run_started_arrives() {
+    let stream = Box::pin(futures::stream::iter(Vec::<Event>::new()));
+    let err = Envelope::from_stream(stream).await.unwrap_err();
+    assert!(matches!(err, EnvelopeError::MissingRunStarted));
+}
+
+#[tokio::test]
+async fn replay_rebuilds_messages_through_the_event_reducer() {
+    let (_, _, events) = sample_run();
+    let envelope = Envelope::new(
+        EnvelopeHeader {
+            thread_id: ThreadId::random(),
+            run_id: RunId::random(),
+        },
+        events,
+    );
+
+    let reducer = envelope.replay().unwrap();
+    assert_eq!(reducer.messages().len(), 1);
+}
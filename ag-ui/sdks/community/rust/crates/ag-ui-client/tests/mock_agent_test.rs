@@ -0,0 +1,143 @@
+use ag_ui_client::agent::{Agent, RunAgentParams};
+use ag_ui_client::core::event::{
+    Event, EventType, RunFinishedEvent, RunStartedEvent, TextMessageContentEvent,
+    TextMessageEndEvent, TextMessageStartEvent, ToolCallArgsEvent, ToolCallEndEvent,
+    ToolCallStartEvent,
+};
+use ag_ui_client::core::types::{MessageId, RunId, Role, ThreadId, ToolCallId};
+use ag_ui_client::mock::{MockAgent, Query, RecordingSubscriber};
+
+mod common;
+use common::{base_event, text_message_script};
+
+#[tokio::test]
+async fn test_mock_agent_replays_scripted_text_message_offline() {
+    let message_id = MessageId::random();
+    let script = text_message_script(message_id, ThreadId::random(), RunId::random());
+
+    let agent = MockAgent::builder().script(script).build();
+    let params = RunAgentParams::new().user("hello");
+
+    let result = agent.run_agent(&params, ()).await;
+
+    assert!(result.is_ok(), "Agent run failed: {:?}", result.err());
+    let result = result.unwrap();
+    assert!(!result.new_messages.is_empty(), "No messages returned");
+    assert!(
+        result
+            .new_messages
+            .iter()
+            .any(|m| m.role() == Role::Assistant),
+        "No assistant messages returned"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_agent_tool_calls() {
+    let thread_id = ThreadId::random();
+    let run_id = RunId::random();
+    let parent_message_id = MessageId::random();
+    let tool_call_id = ToolCallId::random();
+
+    let script = vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: parent_message_id.clone(),
+            role: Role::Assistant,
+        }),
+        Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            tool_call_name: "get_temperature".to_string(),
+            parent_message_id: Some(parent_message_id),
+        }),
+        Event::ToolCallArgs(ToolCallArgsEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            delta: "{\"city\":\"Amsterdam\"}".to_string(),
+        }),
+        Event::ToolCallEnd(ToolCallEndEvent {
+            base: base_event(),
+            tool_call_id,
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id,
+            run_id,
+            result: None,
+        }),
+    ];
+
+    let agent = MockAgent::builder().script(script).build();
+    let params = RunAgentParams::new().user("What's the temperature in Amsterdam?");
+
+    let result = agent.run_agent(&params, ()).await;
+
+    assert!(result.is_ok(), "Agent run failed: {:?}", result.err());
+    let result = result.unwrap();
+
+    let has_tool_calls = result.new_messages.iter().any(|m| {
+        m.tool_calls()
+            .map(|tool_calls| !tool_calls.is_empty())
+            .unwrap_or(false)
+    });
+    assert!(has_tool_calls, "No tool calls were made");
+}
+
+#[tokio::test]
+async fn test_mock_agent_query_filters_to_tool_call_events() {
+    let thread_id = ThreadId::random();
+    let run_id = RunId::random();
+    let mut script = text_message_script(MessageId::random(), thread_id.clone(), run_id.clone());
+    script.insert(
+        script.len() - 1,
+        Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: ToolCallId::random(),
+            tool_call_name: "get_temperature".to_string(),
+            parent_message_id: None,
+        }),
+    );
+
+    let agent = MockAgent::builder()
+        .with_query(Query::only([EventType::ToolCallStart]))
+        .script(script)
+        .build();
+
+    let recorder = RecordingSubscriber::new();
+    let handle = recorder.clone();
+    let params = RunAgentParams::new().user("hello");
+    agent.run_agent(&params, (recorder,)).await.unwrap();
+
+    let observed: Vec<EventType> = handle.events().iter().map(|e| e.event_type()).collect();
+    assert_eq!(
+        observed,
+        vec![EventType::ToolCallStart],
+        "Query should have filtered out every event but ToolCallStart"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_agent_recording_subscriber_collects_emitted_events() {
+    let message_id = MessageId::random();
+    let script = text_message_script(message_id, ThreadId::random(), RunId::random());
+    let script_len = script.len();
+
+    let agent = MockAgent::builder().script(script).build();
+    let recorder = RecordingSubscriber::new();
+    let handle = recorder.clone();
+    let params = RunAgentParams::new().user("hello");
+
+    agent.run_agent(&params, (recorder,)).await.unwrap();
+
+    assert_eq!(
+        handle.events().len(),
+        script_len,
+        "RecordingSubscriber should have observed every scripted event"
+    );
+}
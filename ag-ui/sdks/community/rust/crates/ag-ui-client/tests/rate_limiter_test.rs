@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use ag_ui_client::RateLimiter;
+
+#[test]
+fn rejects_a_zero_max_per_period() {
+    let err = RateLimiter::new(0, Duration::from_secs(1)).unwrap_err();
+    assert!(err.is_user_input());
+}
+
+#[test]
+fn rejects_a_zero_period() {
+    let err = RateLimiter::new(10, Duration::ZERO).unwrap_err();
+    assert!(err.is_user_input());
+}
+
+#[tokio::test]
+async fn allows_a_burst_up_to_the_bucket_capacity_without_waiting() {
+    let limiter = RateLimiter::new(3, Duration::from_secs(60)).unwrap();
+    let start = std::time::Instant::now();
+    limiter.acquire().await;
+    limiter.acquire().await;
+    limiter.acquire().await;
+    // None of these three should have needed to wait out any of the 60s period.
+    assert!(start.elapsed() < Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn delays_once_the_bucket_is_empty() {
+    let limiter = RateLimiter::new(1, Duration::from_millis(200)).unwrap();
+    limiter.acquire().await; // drains the single starting token
+
+    let start = std::time::Instant::now();
+    limiter.acquire().await; // must wait out (most of) the refill period
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[tokio::test]
+async fn shares_the_same_bucket_across_clones() {
+    let limiter = RateLimiter::new(1, Duration::from_millis(200)).unwrap();
+    let clone = limiter.clone();
+    clone.acquire().await; // drains the token via the clone
+
+    let start = std::time::Instant::now();
+    limiter.acquire().await; // the original should see the same, now-empty bucket
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
@@ -0,0 +1,256 @@
+use ag_ui_client::core::event::{
+    Event, StateDeltaEvent, StateSnapshotEvent, TextMessageChunkEvent, TextMessageContentEvent,
+    TextMessageEndEvent, TextMessageStartEvent, ToolCallArgsEvent, ToolCallChunkEvent,
+    ToolCallEndEvent, ToolCallStartEvent,
+};
+use ag_ui_client::core::types::{MessageId, Role, ToolCallId};
+use ag_ui_client::reducer::{EventReducer, ReduceError};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+mod common;
+use common::base_event;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Counter {
+    count: u32,
+}
+
+impl ag_ui_client::core::AgentState for Counter {}
+
+#[test]
+fn folds_a_text_message_into_a_finished_message() {
+    let message_id = MessageId::random();
+    let mut reducer: EventReducer<Counter> = EventReducer::new();
+
+    reducer
+        .apply(&Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            role: Role::Assistant,
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            delta: "hel".to_string(),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            delta: "lo".to_string(),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id,
+        }))
+        .unwrap();
+
+    assert_eq!(reducer.messages().len(), 1);
+    assert_eq!(reducer.messages()[0].content().as_deref(), Some("hello"));
+}
+
+#[test]
+fn folds_a_tool_call_into_its_parent_message() {
+    let parent_id = MessageId::random();
+    let tool_call_id = ToolCallId::random();
+    let mut reducer: EventReducer<Counter> = EventReducer::new();
+
+    reducer
+        .apply(&Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: parent_id.clone(),
+            role: Role::Assistant,
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id: parent_id.clone(),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            tool_call_name: "get_weather".to_string(),
+            parent_message_id: Some(parent_id.clone()),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::ToolCallArgs(ToolCallArgsEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            delta: "{\"city\":\"nyc\"}".to_string(),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::ToolCallEnd(ToolCallEndEvent {
+            base: base_event(),
+            tool_call_id,
+        }))
+        .unwrap();
+
+    let tool_calls = reducer.messages()[0].tool_calls().unwrap();
+    assert_eq!(tool_calls.len(), 1);
+    assert_eq!(tool_calls[0].function.name, "get_weather");
+    assert_eq!(tool_calls[0].function.arguments, "{\"city\":\"nyc\"}");
+}
+
+#[test]
+fn applies_state_snapshot_then_delta() {
+    let mut reducer: EventReducer<Counter> = EventReducer::new();
+
+    reducer
+        .apply(&Event::StateSnapshot(StateSnapshotEvent {
+            base: base_event(),
+            snapshot: Counter { count: 1 },
+        }))
+        .unwrap();
+    assert_eq!(reducer.state().count, 1);
+
+    reducer
+        .apply(&Event::StateDelta(StateDeltaEvent {
+            base: base_event(),
+            delta: vec![json!({"op": "replace", "path": "/count", "value": 2})],
+        }))
+        .unwrap();
+    assert_eq!(reducer.state().count, 2);
+}
+
+#[test]
+fn no_id_text_chunk_continues_the_most_recently_opened_message() {
+    let first_id = MessageId::random();
+    let second_id = MessageId::random();
+    let mut reducer: EventReducer<Counter> = EventReducer::new();
+
+    reducer
+        .apply(&Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: first_id.clone(),
+            role: Role::Assistant,
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: second_id.clone(),
+            role: Role::Assistant,
+        }))
+        .unwrap();
+
+    // No `messageId` on the chunk -- should continue `second_id`, the most recently opened.
+    reducer
+        .apply(&Event::TextMessageChunk(TextMessageChunkEvent {
+            base: base_event(),
+            message_id: None,
+            role: Role::Assistant,
+            delta: Some("world".to_string()),
+        }))
+        .unwrap();
+
+    reducer
+        .apply(&Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id: first_id.clone(),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id: second_id.clone(),
+        }))
+        .unwrap();
+
+    let first = reducer
+        .messages()
+        .iter()
+        .find(|m| m.id() == &first_id)
+        .unwrap();
+    let second = reducer
+        .messages()
+        .iter()
+        .find(|m| m.id() == &second_id)
+        .unwrap();
+    assert_eq!(first.content().as_deref(), Some(""));
+    assert_eq!(second.content().as_deref(), Some("world"));
+}
+
+#[test]
+fn no_id_tool_call_chunk_continues_the_most_recently_opened_tool_call() {
+    let first_id = ToolCallId::random();
+    let second_id = ToolCallId::random();
+    let mut reducer: EventReducer<Counter> = EventReducer::new();
+
+    reducer
+        .apply(&Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: first_id.clone(),
+            tool_call_name: "first_tool".to_string(),
+            parent_message_id: None,
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: second_id.clone(),
+            tool_call_name: "second_tool".to_string(),
+            parent_message_id: None,
+        }))
+        .unwrap();
+
+    // No `toolCallId` on the chunk -- should continue `second_id`, the most recently opened.
+    reducer
+        .apply(&Event::ToolCallChunk(ToolCallChunkEvent {
+            base: base_event(),
+            tool_call_id: None,
+            tool_call_name: None,
+            parent_message_id: None,
+            delta: Some("{\"city\":\"nyc\"}".to_string()),
+        }))
+        .unwrap();
+
+    reducer
+        .apply(&Event::ToolCallEnd(ToolCallEndEvent {
+            base: base_event(),
+            tool_call_id: first_id.clone(),
+        }))
+        .unwrap();
+    reducer
+        .apply(&Event::ToolCallEnd(ToolCallEndEvent {
+            base: base_event(),
+            tool_call_id: second_id.clone(),
+        }))
+        .unwrap();
+
+    let tool_calls: Vec<_> = reducer
+        .messages()
+        .iter()
+        .filter_map(|m| m.tool_calls())
+        .flatten()
+        .collect();
+    let first = tool_calls.iter().find(|tc| tc.id == first_id).unwrap();
+    let second = tool_calls.iter().find(|tc| tc.id == second_id).unwrap();
+    assert_eq!(first.function.arguments, "");
+    assert_eq!(second.function.arguments, "{\"city\":\"nyc\"}");
+}
+
+#[test]
+fn content_for_an_unopened_message_is_an_error() {
+    let mut reducer: EventReducer<Counter> = EventReducer::new();
+
+    let err = reducer
+        .apply(&Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: MessageId::random(),
+            delta: "oops".to_string(),
+        }))
+        .unwrap_err();
+
+    assert!(matches!(err, ReduceError::UnknownMessage(_)));
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use ag_ui_client::error::{AgUiClientError, RateLimitMatcher};
+use ag_ui_client::retry::{DefaultRetryPolicy, ExponentialBackoff, RetryPolicy};
+
+fn server_error() -> AgUiClientError {
+    AgUiClientError::ServerError {
+        status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+        code: "internal_error".to_string(),
+        message: "boom".to_string(),
+        details: None,
+        raw_body: "{}".to_string(),
+        retry_after: None,
+    }
+}
+
+fn bad_request_server_error(code: &str, message: &str) -> AgUiClientError {
+    AgUiClientError::ServerError {
+        status: reqwest::StatusCode::BAD_REQUEST,
+        code: code.to_string(),
+        message: message.to_string(),
+        details: None,
+        raw_body: "{}".to_string(),
+        retry_after: None,
+    }
+}
+
+fn non_retryable_error() -> AgUiClientError {
+    AgUiClientError::config("missing base_url")
+}
+
+#[test]
+fn default_policy_retries_a_retryable_error_under_the_cap() {
+    let policy = DefaultRetryPolicy::new(3);
+    assert!(policy.should_retry(&server_error(), 0));
+    assert!(policy.should_retry(&server_error(), 2));
+    assert!(!policy.should_retry(&server_error(), 3));
+}
+
+#[test]
+fn default_policy_never_retries_a_non_retryable_error() {
+    let policy = DefaultRetryPolicy::new(5);
+    assert!(!policy.should_retry(&non_retryable_error(), 0));
+}
+
+#[test]
+fn default_policy_has_no_backoff_hint() {
+    let policy = DefaultRetryPolicy::default();
+    assert!(policy.backoff_hint(&server_error()).is_none());
+}
+
+#[test]
+fn exponential_backoff_doubles_and_is_capped_at_max_backoff() {
+    let backoff = ExponentialBackoff::new(Duration::from_millis(100), Duration::from_millis(350));
+
+    // Full jitter means each delay is uniform in [0, computed]; assert against the upper bound.
+    assert!(backoff.delay_for(0) <= Duration::from_millis(100));
+    assert!(backoff.delay_for(1) <= Duration::from_millis(200));
+    assert!(backoff.delay_for(2) <= Duration::from_millis(350)); // would be 400ms uncapped
+    assert!(backoff.delay_for(10) <= Duration::from_millis(350));
+}
+
+#[test]
+fn exponential_backoff_default_is_a_quarter_second_base() {
+    let backoff = ExponentialBackoff::default();
+    assert_eq!(backoff.base, Duration::from_millis(250));
+    assert_eq!(backoff.max_backoff, Duration::from_secs(10));
+}
+
+#[test]
+fn default_policy_ignores_a_rate_limit_message_on_a_non_retryable_status_by_default() {
+    // A 400 with a "rate limited" message isn't retryable until a matcher is told to look for it.
+    let policy = DefaultRetryPolicy::new(3);
+    let err = bad_request_server_error("invalid_request", "you have been rate limited, slow down");
+    assert!(!policy.should_retry(&err, 0));
+}
+
+#[test]
+fn default_policy_retries_a_rate_limit_message_with_a_custom_matcher() {
+    let policy = DefaultRetryPolicy::new(3)
+        .with_rate_limit_matcher(RateLimitMatcher::new([], ["rate limited".to_string()]));
+    let err = bad_request_server_error("invalid_request", "you have been rate limited, slow down");
+    assert!(policy.should_retry(&err, 0));
+}
+
+#[test]
+fn default_policy_retries_a_matching_numeric_code_with_a_custom_matcher() {
+    let policy = DefaultRetryPolicy::new(3).with_rate_limit_matcher(RateLimitMatcher::new([1015], []));
+    let err = bad_request_server_error("1015", "over quota");
+    assert!(policy.should_retry(&err, 0));
+
+    let non_matching = bad_request_server_error("1016", "over quota");
+    assert!(!policy.should_retry(&non_matching, 0));
+}
+
+#[test]
+fn rate_limit_matcher_default_recognizes_common_substrings_case_insensitively() {
+    let matcher = RateLimitMatcher::default();
+    assert!(matcher.matches(None, "Rate Limit Exceeded"));
+    assert!(matcher.matches(None, "please slow down, THROTTLED"));
+    assert!(matcher.matches(None, "at capacity, try again later"));
+    assert!(matcher.matches(None, "too many requests"));
+    assert!(!matcher.matches(None, "invalid argument"));
+}
@@ -0,0 +1,79 @@
+use ag_ui_client::agent::{Agent, RunAgentParams};
+use ag_ui_client::core::event::{
+    Event, RunFinishedEvent, RunStartedEvent, StepFinishedEvent, StepStartedEvent,
+    TextMessageContentEvent, TextMessageEndEvent, TextMessageStartEvent,
+};
+use ag_ui_client::core::types::{MessageId, RunId, Role, ThreadId};
+use ag_ui_client::mock::MockAgent;
+use ag_ui_client::server::{LiveRunSubscriber, RunStatus, RunStepStatus};
+
+mod common;
+use common::base_event;
+
+#[tokio::test]
+async fn live_run_subscriber_mirrors_a_full_run_into_run_state() {
+    let message_id = MessageId::random();
+    let thread_id = ThreadId::random();
+    let run_id = RunId::random();
+
+    let script = vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::StepStarted(StepStartedEvent {
+            base: base_event(),
+            step_name: "plan".to_string(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            role: Role::Assistant,
+        }),
+        Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            delta: "hi".to_string(),
+        }),
+        Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id,
+        }),
+        Event::StepFinished(StepFinishedEvent {
+            base: base_event(),
+            step_name: "plan".to_string(),
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id,
+            run_id,
+            result: None,
+        }),
+    ];
+
+    let agent = MockAgent::builder().script(script).build();
+    let (subscriber, mut events): (LiveRunSubscriber, _) = LiveRunSubscriber::new();
+    let run_state = subscriber.run_state();
+    let params = RunAgentParams::new().user("hello");
+
+    agent.run_agent(&params, (subscriber,)).await.unwrap();
+
+    let state = run_state.lock().unwrap();
+    assert_eq!(state.status, RunStatus::Finished);
+    assert_eq!(state.steps.len(), 1);
+    assert_eq!(state.steps[0].name, "plan");
+    assert_eq!(state.steps[0].status, RunStepStatus::Finished);
+    assert_eq!(state.messages.len(), 1);
+    assert_eq!(state.messages[0].content, "hi");
+    assert!(state.buffered_text.is_empty());
+    drop(state);
+
+    // Every scripted event should also have been forwarded for GraphQL subscribers.
+    events.close();
+    let mut forwarded = 0;
+    while futures::StreamExt::next(&mut events).await.is_some() {
+        forwarded += 1;
+    }
+    assert_eq!(forwarded, 7);
+}
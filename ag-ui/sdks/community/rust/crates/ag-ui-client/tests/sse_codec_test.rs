@@ -0,0 +1,84 @@
+use futures::StreamExt;
+
+use ag_ui_client::core::event::{Event, RunStartedEvent, TextMessageContentEvent};
+use ag_ui_client::core::types::{MessageId, RunId, ThreadId};
+use ag_ui_client::sse::SseEvent;
+use ag_ui_client::sse_codec::{decode_sse_event, encode_sse_event, events_typed, SseDecodeError};
+
+mod common;
+use common::base_event;
+
+#[test]
+fn encode_then_decode_round_trips_an_event() {
+    let event = Event::RunStarted(RunStartedEvent {
+        base: base_event(),
+        thread_id: ThreadId::random(),
+        run_id: RunId::random(),
+    });
+
+    let block = encode_sse_event(&event, Some("42")).unwrap();
+    assert!(block.starts_with("event: RUN_STARTED\n"));
+    assert!(block.contains("id: 42\n"));
+    assert!(block.ends_with("\n\n"));
+
+    let data_line = block
+        .lines()
+        .find(|line| line.starts_with("data: "))
+        .unwrap()
+        .strip_prefix("data: ")
+        .unwrap();
+    let raw = SseEvent {
+        event: Some("RUN_STARTED".to_string()),
+        id: Some("42".to_string()),
+        data: data_line.to_string(),
+        retry: None,
+    };
+
+    let decoded: Event = decode_sse_event(&raw).unwrap();
+    assert_eq!(decoded, event);
+}
+
+#[test]
+fn decode_rejects_an_unrecognized_event_name() {
+    let raw = SseEvent {
+        event: Some("NOT_A_REAL_EVENT".to_string()),
+        id: None,
+        data: "{}".to_string(),
+        retry: None,
+    };
+
+    let err = decode_sse_event::<serde_json::Value>(&raw).unwrap_err();
+    assert!(matches!(err, SseDecodeError::UnknownEventType(_)));
+}
+
+#[tokio::test]
+async fn events_typed_decodes_a_stream_of_raw_sse_events() {
+    let message_id = MessageId::random();
+    let event = Event::TextMessageContent(TextMessageContentEvent {
+        base: base_event(),
+        message_id,
+        delta: "hi".to_string(),
+    });
+    let block = encode_sse_event(&event, None).unwrap();
+    let data_line = block
+        .lines()
+        .find(|line| line.starts_with("data: "))
+        .unwrap()
+        .strip_prefix("data: ")
+        .unwrap()
+        .to_string();
+
+    let raw = SseEvent {
+        event: Some("TEXT_MESSAGE_CONTENT".to_string()),
+        id: None,
+        data: data_line,
+        retry: None,
+    };
+
+    let mut stream = Box::pin(events_typed::<serde_json::Value>(futures::stream::iter(
+        vec![Ok(raw)],
+    )));
+
+    let decoded = stream.next().await.unwrap().unwrap();
+    assert_eq!(decoded, event);
+}
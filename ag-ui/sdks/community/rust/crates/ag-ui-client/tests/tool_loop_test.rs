@@ -0,0 +1,210 @@
+use ag_ui_client::core::event::{
+    Event, RunFinishedEvent, RunStartedEvent, TextMessageContentEvent, TextMessageEndEvent,
+    TextMessageStartEvent, ToolCallArgsEvent, ToolCallEndEvent, ToolCallStartEvent,
+};
+use ag_ui_client::core::types::{MessageId, Role, RunId, ThreadId, Tool, ToolCallId};
+use ag_ui_client::mock::MockAgent;
+use ag_ui_client::{RunAgentParams, ToolLoop, ToolLoopError};
+
+mod common;
+use common::base_event;
+
+fn tool_call_script(
+    thread_id: ThreadId,
+    run_id: RunId,
+    parent_message_id: MessageId,
+    tool_call_id: ToolCallId,
+) -> Vec<Event> {
+    vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: parent_message_id.clone(),
+            role: Role::Assistant,
+        }),
+        Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            tool_call_name: "get_temperature".to_string(),
+            parent_message_id: Some(parent_message_id.clone()),
+        }),
+        Event::ToolCallArgs(ToolCallArgsEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            delta: "{\"city\":\"Amsterdam\"}".to_string(),
+        }),
+        Event::ToolCallEnd(ToolCallEndEvent {
+            base: base_event(),
+            tool_call_id,
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id,
+            run_id,
+            result: None,
+        }),
+    ]
+}
+
+fn final_text_script(thread_id: ThreadId, run_id: RunId, message_id: MessageId) -> Vec<Event> {
+    vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            role: Role::Assistant,
+        }),
+        Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: message_id.clone(),
+            delta: "It's 15C in Amsterdam".to_string(),
+        }),
+        Event::TextMessageEnd(TextMessageEndEvent {
+            base: base_event(),
+            message_id,
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id,
+            run_id,
+            result: None,
+        }),
+    ]
+}
+
+fn get_temperature_tool() -> Tool {
+    Tool::new(
+        "get_temperature".to_string(),
+        "Gets the current temperature for a city".to_string(),
+        serde_json::json!({
+            "type": "object",
+            "required": ["city"],
+            "properties": {
+                "city": {"type": "string"},
+            },
+        }),
+    )
+}
+
+#[tokio::test]
+async fn executes_a_registered_tool_and_continues_until_no_calls_are_pending() {
+    let tool_call_id = ToolCallId::random();
+    let script = tool_call_script(
+        ThreadId::random(),
+        RunId::random(),
+        MessageId::random(),
+        tool_call_id,
+    );
+    let final_script = final_text_script(ThreadId::random(), RunId::random(), MessageId::random());
+
+    let agent = MockAgent::builder().script(script).script(final_script).build();
+
+    let tool_loop = ToolLoop::builder()
+        .register(get_temperature_tool(), |args| {
+            Box::pin(async move {
+                let city = args["city"].as_str().unwrap_or_default().to_string();
+                Ok(serde_json::json!({ "city": city, "tempC": 15 }))
+            })
+        })
+        .build();
+
+    let params = RunAgentParams::new().user("What's the temperature in Amsterdam?");
+    let result = tool_loop.run(&agent, params, ()).await.unwrap();
+
+    assert!(
+        result.new_messages.iter().any(|m| matches!(
+            m,
+            ag_ui_client::core::types::Message::Tool { tool_call_id: id, .. } if *id == tool_call_id
+        )),
+        "expected a tool result message to have been fed back into the conversation"
+    );
+    assert!(
+        result
+            .new_messages
+            .iter()
+            .any(|m| m.role() == Role::Assistant
+                && m.content()
+                    .is_some_and(|c| c.contains("15C"))),
+        "expected the final assistant message to be present"
+    );
+}
+
+#[tokio::test]
+async fn errors_on_an_unregistered_tool() {
+    let script = tool_call_script(
+        ThreadId::random(),
+        RunId::random(),
+        MessageId::random(),
+        ToolCallId::random(),
+    );
+    let agent = MockAgent::builder().script(script).build();
+
+    // No tools registered, so `get_temperature` is unknown.
+    let tool_loop = ToolLoop::<serde_json::Value>::builder().build();
+    let params = RunAgentParams::new().user("What's the temperature in Amsterdam?");
+
+    let err = tool_loop.run(&agent, params, ()).await.unwrap_err();
+    assert!(matches!(err, ToolLoopError::UnknownTool(name) if name == "get_temperature"));
+}
+
+#[tokio::test]
+async fn errors_when_arguments_fail_schema_validation() {
+    let thread_id = ThreadId::random();
+    let run_id = RunId::random();
+    let parent_message_id = MessageId::random();
+    let tool_call_id = ToolCallId::random();
+
+    // Missing the required "city" argument.
+    let script = vec![
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: thread_id.clone(),
+            run_id: run_id.clone(),
+        }),
+        Event::TextMessageStart(TextMessageStartEvent {
+            base: base_event(),
+            message_id: parent_message_id.clone(),
+            role: Role::Assistant,
+        }),
+        Event::ToolCallStart(ToolCallStartEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            tool_call_name: "get_temperature".to_string(),
+            parent_message_id: Some(parent_message_id),
+        }),
+        Event::ToolCallArgs(ToolCallArgsEvent {
+            base: base_event(),
+            tool_call_id: tool_call_id.clone(),
+            delta: "{}".to_string(),
+        }),
+        Event::ToolCallEnd(ToolCallEndEvent {
+            base: base_event(),
+            tool_call_id,
+        }),
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id,
+            run_id,
+            result: None,
+        }),
+    ];
+    let agent = MockAgent::builder().script(script).build();
+
+    let tool_loop = ToolLoop::builder()
+        .register(get_temperature_tool(), |_args| {
+            Box::pin(async move { Ok(serde_json::json!({})) })
+        })
+        .build();
+
+    let params = RunAgentParams::new().user("What's the temperature?");
+    let err = tool_loop.run(&agent, params, ()).await.unwrap_err();
+    assert!(matches!(err, ToolLoopError::SchemaValidation { .. }));
+}
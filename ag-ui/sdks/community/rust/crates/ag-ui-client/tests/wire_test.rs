@@ -0,0 +1,115 @@
+use ag_ui_client::core::event::{BaseEvent, Event, RunStartedEvent};
+use ag_ui_client::core::types::{RunId, ThreadId};
+use ag_ui_client::wire::{buffered, EventReader, EventWriter, TransportError, WireFormat};
+
+fn sample_event() -> Event {
+    Event::RunStarted(RunStartedEvent {
+        base: BaseEvent {
+            timestamp: None,
+            raw_event: None,
+        },
+        thread_id: ThreadId::random(),
+        run_id: RunId::random(),
+    })
+}
+
+#[tokio::test]
+async fn round_trips_an_event_over_ndjson() {
+    let mut buf = Vec::new();
+    {
+        let mut writer = EventWriter::new(&mut buf, WireFormat::Ndjson);
+        writer.write_event(&sample_event()).await.unwrap();
+    }
+
+    let mut reader = EventReader::new(buffered(&buf[..]), WireFormat::Ndjson);
+    let event: Event = reader.read_event().await.unwrap().unwrap();
+    assert_eq!(event, sample_event());
+    assert!(reader
+        .read_event::<serde_json::Value>()
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn round_trips_an_event_over_content_length_framing() {
+    let mut buf = Vec::new();
+    {
+        let mut writer = EventWriter::new(&mut buf, WireFormat::ContentLength);
+        writer.write_event(&sample_event()).await.unwrap();
+    }
+
+    let mut reader = EventReader::new(buffered(&buf[..]), WireFormat::ContentLength);
+    let event: Event = reader.read_event().await.unwrap().unwrap();
+    assert_eq!(event, sample_event());
+}
+
+#[tokio::test]
+async fn writes_two_content_length_frames_back_to_back() {
+    let mut buf = Vec::new();
+    {
+        let mut writer = EventWriter::new(&mut buf, WireFormat::ContentLength);
+        writer.write_event(&sample_event()).await.unwrap();
+        writer.write_event(&sample_event()).await.unwrap();
+    }
+
+    let mut reader = EventReader::new(buffered(&buf[..]), WireFormat::ContentLength);
+    let first: Event = reader.read_event().await.unwrap().unwrap();
+    let second: Event = reader.read_event().await.unwrap().unwrap();
+    assert_eq!(first, sample_event());
+    assert_eq!(second, sample_event());
+    assert!(reader
+        .read_event::<serde_json::Value>()
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn rejects_a_line_that_is_not_a_type_tagged_event() {
+    let mut reader = EventReader::new(buffered(&b"not json\n"[..]), WireFormat::Ndjson);
+    let err = reader
+        .read_event::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TransportError::InvalidLine(_)));
+}
+
+#[tokio::test]
+async fn rejects_a_body_shorter_than_the_declared_content_length() {
+    let mut reader = EventReader::new(
+        buffered(&b"Content-Length: 100\r\n\r\n{}"[..]),
+        WireFormat::ContentLength,
+    );
+    let err = reader
+        .read_event::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TransportError::Io(_)));
+}
+
+#[tokio::test]
+async fn rejects_a_missing_content_length_header() {
+    let mut reader = EventReader::new(
+        buffered(&b"X-Other: 1\r\n\r\n{}"[..]),
+        WireFormat::ContentLength,
+    );
+    let err = reader
+        .read_event::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TransportError::MalformedHeader(_)));
+}
+
+#[tokio::test]
+async fn rejects_a_duplicated_content_length_header() {
+    let mut reader = EventReader::new(
+        buffered(&b"Content-Length: 2\r\nContent-Length: 2\r\n\r\n{}"[..]),
+        WireFormat::ContentLength,
+    );
+    let err = reader
+        .read_event::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TransportError::MalformedHeader(_)));
+}
@@ -0,0 +1,254 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::AgUiError;
+
+/// Encodes and decodes values to and from a specific wire format.
+///
+/// Implementations stay generic over `T` so the same encoder works for `RunAgentInput<StateT>`,
+/// `Event<StateT>`, or any other serializable AG-UI type regardless of the concrete state type.
+pub trait Encoder {
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, AgUiError>;
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, AgUiError>;
+}
+
+/// The standard `serde_json` encoder. Used everywhere today and kept as the default.
+pub struct JsonEncoder;
+
+impl Encoder for JsonEncoder {
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, AgUiError> {
+        serde_json::to_vec(value).map_err(AgUiError::from)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, AgUiError> {
+        serde_json::from_slice(bytes).map_err(AgUiError::from)
+    }
+}
+
+/// A compact binary encoder backed by MessagePack, useful for high-volume streaming.
+pub struct MessagePackEncoder;
+
+impl Encoder for MessagePackEncoder {
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, AgUiError> {
+        rmp_serde::to_vec(value)
+            .map_err(|e| AgUiError::new(format!("MessagePack encode error: {e}")))
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, AgUiError> {
+        rmp_serde::from_slice(bytes)
+            .map_err(|e| AgUiError::new(format!("MessagePack decode error: {e}")))
+    }
+}
+
+/// A compact binary encoder backed by `bincode`.
+pub struct BincodeEncoder;
+
+impl Encoder for BincodeEncoder {
+    fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, AgUiError> {
+        bincode::serialize(value).map_err(|e| AgUiError::new(format!("bincode encode error: {e}")))
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, AgUiError> {
+        bincode::deserialize(bytes).map_err(|e| AgUiError::new(format!("bincode decode error: {e}")))
+    }
+}
+
+/// Selects which wire format is in use, and negotiates with HTTP `Content-Type`/`Accept` headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingType {
+    Json,
+    MessagePack,
+    Bincode,
+}
+
+impl EncodingType {
+    /// The MIME type to send as `Content-Type`/`Accept` for this encoding.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            EncodingType::Json => "application/json",
+            EncodingType::MessagePack => "application/x-msgpack",
+            EncodingType::Bincode => "application/x-bincode",
+        }
+    }
+
+    /// Selects an [`EncodingType`] from a response `Content-Type` header value, if recognized.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or("").trim();
+        match mime {
+            "application/json" => Some(EncodingType::Json),
+            "application/x-msgpack" | "application/msgpack" | "application/vnd.msgpack" => {
+                Some(EncodingType::MessagePack)
+            }
+            "application/x-bincode" => Some(EncodingType::Bincode),
+            _ => None,
+        }
+    }
+
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, AgUiError> {
+        match self {
+            EncodingType::Json => JsonEncoder.encode(value),
+            EncodingType::MessagePack => MessagePackEncoder.encode(value),
+            EncodingType::Bincode => BincodeEncoder.encode(value),
+        }
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, AgUiError> {
+        match self {
+            EncodingType::Json => JsonEncoder.decode(bytes),
+            EncodingType::MessagePack => MessagePackEncoder.decode(bytes),
+            EncodingType::Bincode => BincodeEncoder.decode(bytes),
+        }
+    }
+}
+
+impl Default for EncodingType {
+    fn default() -> Self {
+        EncodingType::Json
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard-alphabet base64 encoding, used wherever binary payloads (message attachments,
+/// WebSocket frames) need to travel over a text-only channel without pulling in a dedicated
+/// crate dependency just for this.
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 << 4) | (b1.unwrap_or(0) >> 4)) & 0x3f) as usize] as char);
+        out.push(match b1 {
+            Some(b1) => BASE64_ALPHABET[(((b1 << 2) | (b2.unwrap_or(0) >> 6)) & 0x3f) as usize] as char,
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => BASE64_ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+    out
+}
+
+/// The decoding counterpart to [`base64_encode`]. Accepts either text or raw bytes via
+/// `AsRef<[u8]>` so callers with a `&str` (message attachments) or a `&[u8]` (WebSocket frames)
+/// can both use it directly. Returns `None` on malformed input rather than an error, since callers
+/// typically fold that straight into their own decode-error variant.
+pub fn base64_decode(input: impl AsRef<[u8]>) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let trimmed: Vec<u8> = input
+        .as_ref()
+        .iter()
+        .copied()
+        .filter(|b| *b != b'=' && !b.is_ascii_whitespace())
+        .collect();
+    let mut out = Vec::with_capacity(trimmed.len() * 3 / 4);
+    for chunk in trimmed.chunks(4) {
+        // A lone trailing character carries only 6 bits, not enough for a byte.
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut buf = [0u8; 4];
+        for (i, b) in chunk.iter().enumerate() {
+            buf[i] = value(*b)?;
+        }
+        out.push((buf[0] << 2) | (buf[1] >> 4));
+        if chunk.len() > 2 {
+            out.push((buf[1] << 4) | (buf[2] >> 2));
+        }
+        if chunk.len() > 3 {
+            out.push((buf[2] << 6) | buf[3]);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Sample {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn json_round_trips() {
+        let sample = Sample {
+            name: "widget".to_string(),
+            count: 3,
+        };
+        let bytes = EncodingType::Json.encode(&sample).unwrap();
+        let decoded: Sample = EncodingType::Json.decode(&bytes).unwrap();
+        assert_eq!(sample, decoded);
+    }
+
+    #[test]
+    fn messagepack_round_trips() {
+        let sample = Sample {
+            name: "gadget".to_string(),
+            count: 7,
+        };
+        let bytes = EncodingType::MessagePack.encode(&sample).unwrap();
+        let decoded: Sample = EncodingType::MessagePack.decode(&bytes).unwrap();
+        assert_eq!(sample, decoded);
+    }
+
+    #[test]
+    fn bincode_round_trips() {
+        let sample = Sample {
+            name: "sprocket".to_string(),
+            count: 11,
+        };
+        let bytes = EncodingType::Bincode.encode(&sample).unwrap();
+        let decoded: Sample = EncodingType::Bincode.decode(&bytes).unwrap();
+        assert_eq!(sample, decoded);
+    }
+
+    #[test]
+    fn content_type_selection() {
+        assert_eq!(
+            EncodingType::from_content_type("application/json; charset=utf-8"),
+            Some(EncodingType::Json)
+        );
+        assert_eq!(
+            EncodingType::from_content_type("application/x-msgpack"),
+            Some(EncodingType::MessagePack)
+        );
+        assert_eq!(EncodingType::from_content_type("text/plain"), None);
+    }
+
+    #[test]
+    fn base64_round_trips_arbitrary_bytes() {
+        let data = b"hello, AG-UI!";
+        let encoded = base64_encode(data);
+        assert_eq!(base64_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn base64_decode_accepts_str_and_byte_slice_input() {
+        assert_eq!(base64_decode("aGVsbG8=").unwrap(), b"hello");
+        assert_eq!(base64_decode(b"aGVsbG8=".as_slice()).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn base64_decode_rejects_invalid_characters() {
+        assert!(base64_decode("not valid base64!!").is_none());
+    }
+}
@@ -5,7 +5,7 @@ use crate::types::{MessageId, RunId, ThreadId, ToolCallId};
 use serde::{Deserialize, Serialize};
 
 /// Event types for AG-UI protocol
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventType {
     /// Event indicating the start of a text message
@@ -435,64 +435,150 @@ pub enum Event<StateT: AgentState = JsonValue> {
     StepFinished(StepFinishedEvent),
 }
 
-impl Event {
+/// Implemented by every concrete event payload struct (`TextMessageStartEvent`, `RunStartedEvent`,
+/// ...), giving generic code uniform access to the fields every event shares via [`BaseEvent`]
+/// without having to match on the [`Event`] enum itself.
+pub trait AguiEvent {
+    /// This event's [`EventType`] tag.
+    fn event_type(&self) -> EventType;
+
+    /// The shared base fields (`timestamp`, `raw_event`) carried by every event.
+    fn base(&self) -> &BaseEvent;
+
+    /// Mutable access to the shared base fields, e.g. to stamp a timestamp before sending.
+    fn base_mut(&mut self) -> &mut BaseEvent;
+
+    /// The timestamp this event was recorded at, if any.
+    fn timestamp(&self) -> Option<f64> {
+        self.base().timestamp
+    }
+
+    /// Attaches a timestamp, overwriting any existing one.
+    fn set_timestamp(&mut self, timestamp: f64) {
+        self.base_mut().timestamp = Some(timestamp);
+    }
+
+    /// Attaches the original external payload this event was derived from, overwriting any
+    /// existing one.
+    fn set_raw_event(&mut self, raw_event: JsonValue) {
+        self.base_mut().raw_event = Some(raw_event);
+    }
+}
+
+macro_rules! impl_agui_event {
+    ($ty:ty, $variant:ident) => {
+        impl AguiEvent for $ty {
+            fn event_type(&self) -> EventType {
+                EventType::$variant
+            }
+
+            fn base(&self) -> &BaseEvent {
+                &self.base
+            }
+
+            fn base_mut(&mut self) -> &mut BaseEvent {
+                &mut self.base
+            }
+        }
+    };
+}
+
+impl_agui_event!(TextMessageStartEvent, TextMessageStart);
+impl_agui_event!(TextMessageContentEvent, TextMessageContent);
+impl_agui_event!(TextMessageEndEvent, TextMessageEnd);
+impl_agui_event!(TextMessageChunkEvent, TextMessageChunk);
+impl_agui_event!(ThinkingTextMessageStartEvent, ThinkingTextMessageStart);
+impl_agui_event!(ThinkingTextMessageContentEvent, ThinkingTextMessageContent);
+impl_agui_event!(ThinkingTextMessageEndEvent, ThinkingTextMessageEnd);
+impl_agui_event!(ToolCallStartEvent, ToolCallStart);
+impl_agui_event!(ToolCallArgsEvent, ToolCallArgs);
+impl_agui_event!(ToolCallEndEvent, ToolCallEnd);
+impl_agui_event!(ToolCallChunkEvent, ToolCallChunk);
+impl_agui_event!(ToolCallResultEvent, ToolCallResult);
+impl_agui_event!(ThinkingStartEvent, ThinkingStart);
+impl_agui_event!(ThinkingEndEvent, ThinkingEnd);
+impl_agui_event!(StateDeltaEvent, StateDelta);
+impl_agui_event!(MessagesSnapshotEvent, MessagesSnapshot);
+impl_agui_event!(RawEvent, Raw);
+impl_agui_event!(CustomEvent, Custom);
+impl_agui_event!(RunStartedEvent, RunStarted);
+impl_agui_event!(RunFinishedEvent, RunFinished);
+impl_agui_event!(RunErrorEvent, RunError);
+impl_agui_event!(StepStartedEvent, StepStarted);
+impl_agui_event!(StepFinishedEvent, StepFinished);
+
+impl<StateT: AgentState> AguiEvent for StateSnapshotEvent<StateT> {
+    fn event_type(&self) -> EventType {
+        EventType::StateSnapshot
+    }
+
+    fn base(&self) -> &BaseEvent {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut BaseEvent {
+        &mut self.base
+    }
+}
+
+impl<StateT: AgentState> Event<StateT> {
     /// Get the event type
     pub fn event_type(&self) -> EventType {
         match self {
-            Event::TextMessageStart(_) => EventType::TextMessageStart,
-            Event::TextMessageContent(_) => EventType::TextMessageContent,
-            Event::TextMessageEnd(_) => EventType::TextMessageEnd,
-            Event::TextMessageChunk(_) => EventType::TextMessageChunk,
-            Event::ThinkingTextMessageStart(_) => EventType::ThinkingTextMessageStart,
-            Event::ThinkingTextMessageContent(_) => EventType::ThinkingTextMessageContent,
-            Event::ThinkingTextMessageEnd(_) => EventType::ThinkingTextMessageEnd,
-            Event::ToolCallStart(_) => EventType::ToolCallStart,
-            Event::ToolCallArgs(_) => EventType::ToolCallArgs,
-            Event::ToolCallEnd(_) => EventType::ToolCallEnd,
-            Event::ToolCallChunk(_) => EventType::ToolCallChunk,
-            Event::ToolCallResult(_) => EventType::ToolCallResult,
-            Event::ThinkingStart(_) => EventType::ThinkingStart,
-            Event::ThinkingEnd(_) => EventType::ThinkingEnd,
-            Event::StateSnapshot(_) => EventType::StateSnapshot,
-            Event::StateDelta(_) => EventType::StateDelta,
-            Event::MessagesSnapshot(_) => EventType::MessagesSnapshot,
-            Event::Raw(_) => EventType::Raw,
-            Event::Custom(_) => EventType::Custom,
-            Event::RunStarted(_) => EventType::RunStarted,
-            Event::RunFinished(_) => EventType::RunFinished,
-            Event::RunError(_) => EventType::RunError,
-            Event::StepStarted(_) => EventType::StepStarted,
-            Event::StepFinished(_) => EventType::StepFinished,
+            Event::TextMessageStart(e) => e.event_type(),
+            Event::TextMessageContent(e) => e.event_type(),
+            Event::TextMessageEnd(e) => e.event_type(),
+            Event::TextMessageChunk(e) => e.event_type(),
+            Event::ThinkingTextMessageStart(e) => e.event_type(),
+            Event::ThinkingTextMessageContent(e) => e.event_type(),
+            Event::ThinkingTextMessageEnd(e) => e.event_type(),
+            Event::ToolCallStart(e) => e.event_type(),
+            Event::ToolCallArgs(e) => e.event_type(),
+            Event::ToolCallEnd(e) => e.event_type(),
+            Event::ToolCallChunk(e) => e.event_type(),
+            Event::ToolCallResult(e) => e.event_type(),
+            Event::ThinkingStart(e) => e.event_type(),
+            Event::ThinkingEnd(e) => e.event_type(),
+            Event::StateSnapshot(e) => e.event_type(),
+            Event::StateDelta(e) => e.event_type(),
+            Event::MessagesSnapshot(e) => e.event_type(),
+            Event::Raw(e) => e.event_type(),
+            Event::Custom(e) => e.event_type(),
+            Event::RunStarted(e) => e.event_type(),
+            Event::RunFinished(e) => e.event_type(),
+            Event::RunError(e) => e.event_type(),
+            Event::StepStarted(e) => e.event_type(),
+            Event::StepFinished(e) => e.event_type(),
         }
     }
 
     /// Get the timestamp if available
     pub fn timestamp(&self) -> Option<f64> {
         match self {
-            Event::TextMessageStart(e) => e.base.timestamp,
-            Event::TextMessageContent(e) => e.base.timestamp,
-            Event::TextMessageEnd(e) => e.base.timestamp,
-            Event::TextMessageChunk(e) => e.base.timestamp,
-            Event::ThinkingTextMessageStart(e) => e.base.timestamp,
-            Event::ThinkingTextMessageContent(e) => e.base.timestamp,
-            Event::ThinkingTextMessageEnd(e) => e.base.timestamp,
-            Event::ToolCallStart(e) => e.base.timestamp,
-            Event::ToolCallArgs(e) => e.base.timestamp,
-            Event::ToolCallEnd(e) => e.base.timestamp,
-            Event::ToolCallChunk(e) => e.base.timestamp,
-            Event::ToolCallResult(e) => e.base.timestamp,
-            Event::ThinkingStart(e) => e.base.timestamp,
-            Event::ThinkingEnd(e) => e.base.timestamp,
-            Event::StateSnapshot(e) => e.base.timestamp,
-            Event::StateDelta(e) => e.base.timestamp,
-            Event::MessagesSnapshot(e) => e.base.timestamp,
-            Event::Raw(e) => e.base.timestamp,
-            Event::Custom(e) => e.base.timestamp,
-            Event::RunStarted(e) => e.base.timestamp,
-            Event::RunFinished(e) => e.base.timestamp,
-            Event::RunError(e) => e.base.timestamp,
-            Event::StepStarted(e) => e.base.timestamp,
-            Event::StepFinished(e) => e.base.timestamp,
+            Event::TextMessageStart(e) => e.timestamp(),
+            Event::TextMessageContent(e) => e.timestamp(),
+            Event::TextMessageEnd(e) => e.timestamp(),
+            Event::TextMessageChunk(e) => e.timestamp(),
+            Event::ThinkingTextMessageStart(e) => e.timestamp(),
+            Event::ThinkingTextMessageContent(e) => e.timestamp(),
+            Event::ThinkingTextMessageEnd(e) => e.timestamp(),
+            Event::ToolCallStart(e) => e.timestamp(),
+            Event::ToolCallArgs(e) => e.timestamp(),
+            Event::ToolCallEnd(e) => e.timestamp(),
+            Event::ToolCallChunk(e) => e.timestamp(),
+            Event::ToolCallResult(e) => e.timestamp(),
+            Event::ThinkingStart(e) => e.timestamp(),
+            Event::ThinkingEnd(e) => e.timestamp(),
+            Event::StateSnapshot(e) => e.timestamp(),
+            Event::StateDelta(e) => e.timestamp(),
+            Event::MessagesSnapshot(e) => e.timestamp(),
+            Event::Raw(e) => e.timestamp(),
+            Event::Custom(e) => e.timestamp(),
+            Event::RunStarted(e) => e.timestamp(),
+            Event::RunFinished(e) => e.timestamp(),
+            Event::RunError(e) => e.timestamp(),
+            Event::StepStarted(e) => e.timestamp(),
+            Event::StepFinished(e) => e.timestamp(),
         }
     }
 }
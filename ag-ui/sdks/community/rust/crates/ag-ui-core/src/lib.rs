@@ -1,12 +1,18 @@
 #![doc = include_str!("../README.md")]
 
+pub mod encoding;
 pub mod error;
 pub mod event;
+pub mod schema;
 mod state;
 pub mod types;
+mod validate;
 
+pub use encoding::{Encoder, EncodingType, base64_decode, base64_encode};
 pub use error::{AgUiError, Result};
-pub use state::{AgentState, FwdProps};
+pub use schema::{ValidationError, validate_against_schema};
+pub use state::{AgentState, FwdProps, StateDeltaError, apply_state_delta};
+pub use validate::{EventStreamValidator, StreamValidationError};
 
 /// Re-export to ensure the same type is used
 pub use serde_json::Value as JsonValue;
@@ -0,0 +1,93 @@
+use serde_json::Value as JsonValue;
+
+/// Errors validating a JSON value against a pragmatic subset of JSON Schema.
+///
+/// Returned by [`crate::types::FunctionCall::validate`] when a model's tool-call arguments don't
+/// satisfy the tool's declared `parameters` schema.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ValidationError {
+    /// A property listed in the schema's `required` array is missing from the value.
+    #[error("missing required property {0:?}")]
+    MissingProperty(String),
+
+    /// The value's JSON type doesn't match the schema's `type`.
+    #[error("expected type {expected:?}, got {actual}")]
+    TypeMismatch { expected: String, actual: JsonValue },
+
+    /// The value isn't one of the schema's `enum` members.
+    #[error("{value} is not one of the allowed values {allowed:?}")]
+    NotInEnum {
+        value: JsonValue,
+        allowed: Vec<JsonValue>,
+    },
+}
+
+/// Validates `value` against a pragmatic subset of JSON Schema covering what AG-UI tool
+/// `parameters` schemas actually use: `type`, `enum`, object `properties`/`required`, and `items`
+/// for arrays. Not a general-purpose JSON Schema validator.
+pub fn validate_against_schema(schema: &JsonValue, value: &JsonValue) -> Result<(), ValidationError> {
+    let Some(schema) = schema.as_object() else {
+        return Ok(());
+    };
+
+    if let Some(expected_type) = schema.get("type").and_then(JsonValue::as_str)
+        && !matches_json_type(expected_type, value)
+    {
+        return Err(ValidationError::TypeMismatch {
+            expected: expected_type.to_string(),
+            actual: value.clone(),
+        });
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(JsonValue::as_array)
+        && !allowed.contains(value)
+    {
+        return Err(ValidationError::NotInEnum {
+            value: value.clone(),
+            allowed: allowed.clone(),
+        });
+    }
+
+    if let Some(required) = schema.get("required").and_then(JsonValue::as_array) {
+        let object = value.as_object();
+        for key in required {
+            let Some(key) = key.as_str() else { continue };
+            if !object.is_some_and(|o| o.contains_key(key)) {
+                return Err(ValidationError::MissingProperty(key.to_string()));
+            }
+        }
+    }
+
+    if let Some(properties) = schema.get("properties").and_then(JsonValue::as_object)
+        && let Some(object) = value.as_object()
+    {
+        for (key, property_schema) in properties {
+            if let Some(property_value) = object.get(key) {
+                validate_against_schema(property_schema, property_value)?;
+            }
+        }
+    }
+
+    if let Some(items_schema) = schema.get("items")
+        && let Some(items) = value.as_array()
+    {
+        for item in items {
+            validate_against_schema(items_schema, item)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_json_type(expected: &str, value: &JsonValue) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
@@ -1,3 +1,4 @@
+use json_patch::PatchOperation;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::fmt::Debug;
@@ -19,3 +20,42 @@ pub trait FwdProps:
 
 impl FwdProps for JsonValue {}
 impl FwdProps for () {}
+
+/// Errors applying an RFC 6902 JSON Patch (a `StateDeltaEvent`'s `delta`) to a typed [`AgentState`].
+#[derive(Debug, thiserror::Error)]
+pub enum StateDeltaError {
+    /// `delta` doesn't parse as a list of RFC 6902 patch operations.
+    #[error("delta is not a valid RFC 6902 JSON Patch operation list: {0}")]
+    InvalidPatch(serde_json::Error),
+    /// Applying the patch failed, e.g. an `add`/`replace`/`move`/`copy` path didn't resolve, or a
+    /// `test` operation didn't match.
+    #[error("failed to apply JSON Patch: {0}")]
+    PatchFailed(#[from] json_patch::PatchError),
+    /// The patched JSON no longer deserializes into the target `StateT` shape.
+    #[error("patched state does not deserialize into the target shape: {0}")]
+    Deserialize(serde_json::Error),
+}
+
+/// Applies an RFC 6902 JSON Patch (`add`/`remove`/`replace`/`move`/`copy`/`test`, including `-`
+/// array-append and `test`-failure aborting the whole patch) to `base`, re-deserializing the
+/// result back into `StateT`.
+///
+/// This is the same application `ag-ui-client`'s default run loop performs for every
+/// `StateDeltaEvent`; it's exposed here so other consumers -- custom event reducers, or
+/// subscribers that want typed progress without hand-parsing patch ops themselves -- can apply a
+/// delta the same way and get a fully-typed `StateT` back instead of raw JSON.
+pub fn apply_state_delta<StateT>(
+    base: &StateT,
+    delta: &[JsonValue],
+) -> Result<StateT, StateDeltaError>
+where
+    StateT: AgentState,
+{
+    let mut value = serde_json::to_value(base).map_err(StateDeltaError::Deserialize)?;
+
+    let patches: Vec<PatchOperation> = serde_json::from_value(JsonValue::Array(delta.to_vec()))
+        .map_err(StateDeltaError::InvalidPatch)?;
+    json_patch::patch(&mut value, &patches)?;
+
+    serde_json::from_value(value).map_err(StateDeltaError::Deserialize)
+}
@@ -85,7 +85,7 @@ define_id_type!(MessageId);
 
 /// A tool call ID.
 /// Used by some providers to denote a specific ID for a tool call generation, where the result of the tool call must also use this ID.
-#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Deserialize, Serialize, Clone)]
 pub struct ToolCallId(String);
 
 /// Tool Call ID
@@ -106,6 +106,13 @@ impl Deref for ToolCallId {
     }
 }
 
+/// Allows printing the ID.
+impl std::fmt::Display for ToolCallId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Test whether tool call ID has same format as rest of AG-UI
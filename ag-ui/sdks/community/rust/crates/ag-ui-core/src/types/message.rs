@@ -1,6 +1,12 @@
+use crate::encoding::{base64_decode, base64_encode};
+use crate::schema::{ValidationError, validate_against_schema};
 use crate::types::ids::{MessageId, ToolCallId};
 use crate::types::tool::ToolCall;
+use bytes::Bytes;
+use serde::de::{self, Deserializer, DeserializeOwned};
+use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 
 /// A generated function call from a model
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -10,6 +16,278 @@ pub struct FunctionCall {
     pub arguments: String,
 }
 
+impl FunctionCall {
+    /// Parses `arguments` as a raw [`JsonValue`], without committing to a target shape.
+    pub fn arguments_value(&self) -> Result<JsonValue, serde_json::Error> {
+        serde_json::from_str(&self.arguments)
+    }
+
+    /// Parses `arguments` directly into `T`, for callers that know the tool's argument shape
+    /// ahead of time and want it without an intermediate [`JsonValue`].
+    pub fn arguments_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.arguments)
+    }
+
+    /// Checks `arguments` against a tool's declared JSON Schema `parameters` (required fields,
+    /// types, enum membership) before the call is executed, so malformed model output is caught
+    /// as a [`ValidationError`] instead of surfacing as a downstream deserialization panic.
+    pub fn validate(&self, schema: &JsonValue) -> Result<(), FunctionCallValidationError> {
+        let value = self
+            .arguments_value()
+            .map_err(FunctionCallValidationError::Json)?;
+        validate_against_schema(schema, &value).map_err(FunctionCallValidationError::Schema)
+    }
+}
+
+/// Errors from [`FunctionCall::validate`].
+#[derive(Debug, thiserror::Error)]
+pub enum FunctionCallValidationError {
+    /// `arguments` didn't parse as JSON at all.
+    #[error("arguments did not parse as JSON: {0}")]
+    Json(serde_json::Error),
+
+    /// `arguments` parsed, but didn't satisfy the tool's schema.
+    #[error("arguments failed schema validation: {0}")]
+    Schema(#[from] ValidationError),
+}
+
+/// A single piece of message content: plain text, an embedded binary attachment, or an image
+/// referenced by URL (remote `http(s)://` link or an already-encoded `data:` URI).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentPart {
+    Text(String),
+    Binary { mime_type: String, data: Bytes },
+    ImageUrl { url: String, detail: Option<String> },
+}
+
+/// Ordered message content that can interleave text and binary attachments (images, audio,
+/// tool output blobs, ...) at arbitrary positions.
+///
+/// Content made up of a single text part serializes as a bare JSON string -- the exact shape
+/// every message used before attachments existed -- so plain-text messages are unaffected on
+/// the wire. Once a binary part is present, content instead serializes as an object: `parts` is
+/// the ordered list, with each binary part replaced by a placeholder `{"_binary": <index>,
+/// "mime": "..."}`, and `attachments` is a side channel of the corresponding base64-encoded
+/// payloads, indexed in the order their placeholders appear. Deserializing splices each
+/// attachment back into its placeholder's original position.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MessageContent {
+    pub parts: Vec<ContentPart>,
+}
+
+impl MessageContent {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            parts: vec![ContentPart::Text(text.into())],
+        }
+    }
+
+    pub fn push_text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.parts.push(ContentPart::Text(text.into()));
+        self
+    }
+
+    pub fn push_binary(&mut self, mime_type: impl Into<String>, data: impl Into<Bytes>) -> &mut Self {
+        self.parts.push(ContentPart::Binary {
+            mime_type: mime_type.into(),
+            data: data.into(),
+        });
+        self
+    }
+
+    /// Appends an image referenced by URL (remote `http(s)://` link or a `data:` URI).
+    pub fn push_image_url(&mut self, url: impl Into<String>, detail: Option<String>) -> &mut Self {
+        self.parts.push(ContentPart::ImageUrl {
+            url: url.into(),
+            detail,
+        });
+        self
+    }
+
+    /// Appends to (or starts) a trailing text part, mirroring `String::push_str` so streamed
+    /// text deltas can be folded in without callers having to match on `ContentPart`.
+    pub fn push_str(&mut self, text: &str) {
+        match self.parts.last_mut() {
+            Some(ContentPart::Text(existing)) => existing.push_str(text),
+            _ => self.parts.push(ContentPart::Text(text.to_string())),
+        }
+    }
+
+    /// Content made up of a single image referenced by URL.
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self {
+            parts: vec![ContentPart::ImageUrl {
+                url: url.into(),
+                detail: None,
+            }],
+        }
+    }
+
+    /// Reads `path` from disk, guesses its MIME type from the file extension, and wraps the
+    /// base64-encoded bytes in a `data:` URL image part -- for attaching a local image file
+    /// without standing up a URL for it.
+    pub fn image_from_path(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let mime_type = guess_mime_type(path);
+        Ok(Self {
+            parts: vec![ContentPart::ImageUrl {
+                url: format!("data:{mime_type};base64,{}", base64_encode(&data)),
+                detail: None,
+            }],
+        })
+    }
+
+    /// Concatenates all text parts, dropping binary/image attachments -- for callers (prompt
+    /// construction, logging) that only ever dealt with a flat string.
+    pub fn as_text(&self) -> String {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                ContentPart::Text(text) => Some(text.as_str()),
+                ContentPart::Binary { .. } | ContentPart::ImageUrl { .. } => None,
+            })
+            .collect::<Vec<_>>()
+            .concat()
+    }
+}
+
+/// Guesses a MIME type from a file extension, covering the image formats vision-capable models
+/// commonly accept. Falls back to `application/octet-stream` for anything unrecognized.
+fn guess_mime_type(path: &std::path::Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        Some("bmp") => "image/bmp",
+        Some("pdf") => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+impl From<String> for MessageContent {
+    fn from(text: String) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<&str> for MessageContent {
+    fn from(text: &str) -> Self {
+        Self::text(text)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum WirePartRef<'a> {
+    BinaryRef { _binary: usize, mime: &'a str },
+    ImageUrl { url: &'a str, detail: Option<&'a str> },
+    Text(&'a str),
+}
+
+#[derive(Serialize)]
+struct WireContentRef<'a> {
+    parts: Vec<WirePartRef<'a>>,
+    attachments: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WirePartOwned {
+    BinaryRef { _binary: usize, mime: String },
+    ImageUrl { url: String, detail: Option<String> },
+    Text(String),
+}
+
+#[derive(Deserialize)]
+struct WireContentOwned {
+    parts: Vec<WirePartOwned>,
+    #[serde(default)]
+    attachments: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ContentRepr {
+    Plain(String),
+    Structured(WireContentOwned),
+}
+
+impl Serialize for MessageContent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let needs_structured = self
+            .parts
+            .iter()
+            .any(|part| !matches!(part, ContentPart::Text(_)));
+        if !needs_structured {
+            return serializer.serialize_str(&self.as_text());
+        }
+
+        let mut parts = Vec::with_capacity(self.parts.len());
+        let mut attachments = Vec::new();
+        for part in &self.parts {
+            match part {
+                ContentPart::Text(text) => parts.push(WirePartRef::Text(text)),
+                ContentPart::Binary { mime_type, data } => {
+                    let index = attachments.len();
+                    attachments.push(base64_encode(data));
+                    parts.push(WirePartRef::BinaryRef {
+                        _binary: index,
+                        mime: mime_type,
+                    });
+                }
+                ContentPart::ImageUrl { url, detail } => parts.push(WirePartRef::ImageUrl {
+                    url,
+                    detail: detail.as_deref(),
+                }),
+            }
+        }
+
+        WireContentRef { parts, attachments }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ContentRepr::deserialize(deserializer)? {
+            ContentRepr::Plain(text) => Ok(MessageContent::text(text)),
+            ContentRepr::Structured(wire) => {
+                let mut parts = Vec::with_capacity(wire.parts.len());
+                for part in wire.parts {
+                    match part {
+                        WirePartOwned::Text(text) => parts.push(ContentPart::Text(text)),
+                        WirePartOwned::BinaryRef { _binary, mime } => {
+                            let raw = wire.attachments.get(_binary).ok_or_else(|| {
+                                de::Error::custom(format!(
+                                    "binary content part references missing attachment index {_binary}"
+                                ))
+                            })?;
+                            let data = base64_decode(raw).ok_or_else(|| {
+                                de::Error::custom("attachment payload is not valid base64")
+                            })?;
+                            parts.push(ContentPart::Binary {
+                                mime_type: mime,
+                                data: Bytes::from(data),
+                            });
+                        }
+                        WirePartOwned::ImageUrl { url, detail } => {
+                            parts.push(ContentPart::ImageUrl { url, detail });
+                        }
+                    }
+                }
+                Ok(MessageContent { parts })
+            }
+        }
+    }
+}
+
 /// Message role.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -46,7 +324,7 @@ pub struct BaseMessage {
     pub id: MessageId,
     pub role: Role,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
+    pub content: Option<MessageContent>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
@@ -58,17 +336,17 @@ pub struct DeveloperMessage {
     pub id: MessageId,
     #[serde(default = "Role::developer")]
     pub role: Role, // Always Role::Developer
-    pub content: String,
+    pub content: MessageContent,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
 impl DeveloperMessage {
-    pub fn new(id: impl Into<MessageId>, content: String) -> Self {
+    pub fn new(id: impl Into<MessageId>, content: impl Into<MessageContent>) -> Self {
         Self {
             id: id.into(),
             role: Role::Developer,
-            content,
+            content: content.into(),
             name: None,
         }
     }
@@ -85,17 +363,17 @@ pub struct SystemMessage {
     pub id: MessageId,
     #[serde(default = "Role::system")]
     pub role: Role, // Always Role::System
-    pub content: String,
+    pub content: MessageContent,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
 impl SystemMessage {
-    pub fn new(id: impl Into<MessageId>, content: String) -> Self {
+    pub fn new(id: impl Into<MessageId>, content: impl Into<MessageContent>) -> Self {
         Self {
             id: id.into(),
             role: Role::System,
-            content,
+            content: content.into(),
             name: None,
         }
     }
@@ -113,7 +391,7 @@ pub struct AssistantMessage {
     #[serde(default = "Role::assistant")]
     pub role: Role, // Always Role::Assistant
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
+    pub content: Option<MessageContent>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(rename = "toolCalls", skip_serializing_if = "Option::is_none")]
@@ -131,8 +409,8 @@ impl AssistantMessage {
         }
     }
 
-    pub fn with_content(mut self, content: String) -> Self {
-        self.content = Some(content);
+    pub fn with_content(mut self, content: impl Into<MessageContent>) -> Self {
+        self.content = Some(content.into());
         self
     }
 
@@ -153,17 +431,17 @@ pub struct UserMessage {
     pub id: MessageId,
     #[serde(default = "Role::user")]
     pub role: Role, // Always Role::User
-    pub content: String,
+    pub content: MessageContent,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
 impl UserMessage {
-    pub fn new(id: impl Into<MessageId>, content: String) -> Self {
+    pub fn new(id: impl Into<MessageId>, content: impl Into<MessageContent>) -> Self {
         Self {
             id: id.into(),
             role: Role::User,
-            content,
+            content: content.into(),
             name: None,
         }
     }
@@ -172,13 +450,38 @@ impl UserMessage {
         self.name = Some(name);
         self
     }
+
+    /// Builds a user message whose content is a single image referenced by URL (a remote
+    /// `http(s)://` link or an already-encoded `data:` URI).
+    pub fn with_image(id: impl Into<MessageId>, url: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            role: Role::User,
+            content: MessageContent::image_url(url),
+            name: None,
+        }
+    }
+
+    /// Builds a user message embedding a local image file as a base64 `data:` URL, inferring its
+    /// MIME type from the file extension. See [`MessageContent::image_from_path`].
+    pub fn with_image_file(
+        id: impl Into<MessageId>,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<Self> {
+        Ok(Self {
+            id: id.into(),
+            role: Role::User,
+            content: MessageContent::image_from_path(path)?,
+            name: None,
+        })
+    }
 }
 
 /// A tool call result.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ToolMessage {
     pub id: MessageId,
-    pub content: String,
+    pub content: MessageContent,
     #[serde(default = "Role::tool")]
     pub role: Role, // Always Role::Tool
     #[serde(rename = "toolCallId")]
@@ -190,12 +493,12 @@ pub struct ToolMessage {
 impl ToolMessage {
     pub fn new(
         id: impl Into<MessageId>,
-        content: String,
+        content: impl Into<MessageContent>,
         tool_call_id: impl Into<ToolCallId>,
     ) -> Self {
         Self {
             id: id.into(),
-            content,
+            content: content.into(),
             role: Role::Tool,
             tool_call_id: tool_call_id.into(),
             error: None,
@@ -208,26 +511,37 @@ impl ToolMessage {
     }
 }
 
+impl From<ToolMessage> for Message {
+    fn from(message: ToolMessage) -> Self {
+        Message::Tool {
+            id: message.id,
+            content: message.content,
+            tool_call_id: message.tool_call_id,
+            error: message.error,
+        }
+    }
+}
+
 /// Represents the different type of messages that you might receive, but as an enum.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "role", rename_all = "lowercase")]
 pub enum Message {
     Developer {
         id: MessageId,
-        content: String,
+        content: MessageContent,
         #[serde(skip_serializing_if = "Option::is_none")]
         name: Option<String>,
     },
     System {
         id: MessageId,
-        content: String,
+        content: MessageContent,
         #[serde(skip_serializing_if = "Option::is_none")]
         name: Option<String>,
     },
     Assistant {
         id: MessageId,
         #[serde(skip_serializing_if = "Option::is_none")]
-        content: Option<String>,
+        content: Option<MessageContent>,
         #[serde(skip_serializing_if = "Option::is_none")]
         name: Option<String>,
         #[serde(rename = "toolCalls", skip_serializing_if = "Option::is_none")]
@@ -235,13 +549,13 @@ pub enum Message {
     },
     User {
         id: MessageId,
-        content: String,
+        content: MessageContent,
         #[serde(skip_serializing_if = "Option::is_none")]
         name: Option<String>,
     },
     Tool {
         id: MessageId,
-        content: String,
+        content: MessageContent,
         #[serde(rename = "toolCallId")]
         tool_call_id: ToolCallId,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -250,32 +564,33 @@ pub enum Message {
 }
 
 impl Message {
-    pub fn new<S: AsRef<str>>(role: Role, id: impl Into<MessageId>, content: S) -> Self {
+    pub fn new(role: Role, id: impl Into<MessageId>, content: impl Into<MessageContent>) -> Self {
+        let content = content.into();
         match role {
             Role::Developer => Self::Developer {
                 id: id.into(),
-                content: content.as_ref().to_string(),
+                content,
                 name: None,
             },
             Role::System => Self::System {
                 id: id.into(),
-                content: content.as_ref().to_string(),
+                content,
                 name: None,
             },
             Role::Assistant => Self::Assistant {
                 id: id.into(),
-                content: Some(content.as_ref().to_string()),
+                content: Some(content),
                 name: None,
                 tool_calls: None,
             },
             Role::User => Self::User {
                 id: id.into(),
-                content: content.as_ref().to_string(),
+                content,
                 name: None,
             },
             Role::Tool => Self::Tool {
                 id: id.into(),
-                content: content.as_ref().to_string(),
+                content,
                 tool_call_id: ToolCallId::random(),
                 error: None,
             },
@@ -283,27 +598,27 @@ impl Message {
     }
 
     /// Returns a User message with a random ID and the given content
-    pub fn new_user<S: AsRef<str>>(content: S) -> Self {
+    pub fn new_user(content: impl Into<MessageContent>) -> Self {
         Self::new(Role::User, MessageId::random(), content)
     }
 
     /// Returns a Tool message with a random ID and the given content
-    pub fn new_tool<S: AsRef<str>>(content: S) -> Self {
+    pub fn new_tool(content: impl Into<MessageContent>) -> Self {
         Self::new(Role::Tool, MessageId::random(), content)
     }
 
     /// Returns a System message with a random ID and the given content
-    pub fn new_system<S: AsRef<str>>(content: S) -> Self {
+    pub fn new_system(content: impl Into<MessageContent>) -> Self {
         Self::new(Role::System, MessageId::random(), content)
     }
 
     /// Returns an Assistant message with a random ID and the given content
-    pub fn new_assistant<S: AsRef<str>>(content: S) -> Self {
+    pub fn new_assistant(content: impl Into<MessageContent>) -> Self {
         Self::new(Role::Assistant, MessageId::random(), content)
     }
 
     /// Returns a Developer message with a random ID and the given content
-    pub fn new_developer<S: AsRef<str>>(content: S) -> Self {
+    pub fn new_developer(content: impl Into<MessageContent>) -> Self {
         Self::new(Role::Developer, MessageId::random(), content)
     }
 
@@ -336,17 +651,18 @@ impl Message {
             Message::Tool { .. } => Role::Tool,
         }
     }
-    pub fn content(&self) -> Option<&str> {
+    /// Returns the message's content flattened to plain text, dropping any binary attachments.
+    pub fn content(&self) -> Option<String> {
         match self {
-            Message::Developer { content, .. } => Some(content),
-            Message::System { content, .. } => Some(content),
-            Message::User { content, .. } => Some(content),
-            Message::Tool { content, .. } => Some(content),
-            Message::Assistant { content, .. } => content.as_deref(),
+            Message::Developer { content, .. } => Some(content.as_text()),
+            Message::System { content, .. } => Some(content.as_text()),
+            Message::User { content, .. } => Some(content.as_text()),
+            Message::Tool { content, .. } => Some(content.as_text()),
+            Message::Assistant { content, .. } => content.as_ref().map(MessageContent::as_text),
         }
     }
 
-    pub fn content_mut(&mut self) -> Option<&mut String> {
+    pub fn content_mut(&mut self) -> Option<&mut MessageContent> {
         match self {
             Message::Developer { content, .. }
             | Message::System { content, .. }
@@ -354,7 +670,7 @@ impl Message {
             | Message::Tool { content, .. } => Some(content),
             Message::Assistant { content, .. } => {
                 if content.is_none() {
-                    *content = Some(String::new());
+                    *content = Some(MessageContent::default());
                 }
                 content.as_mut()
             }
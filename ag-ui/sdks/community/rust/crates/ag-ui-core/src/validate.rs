@@ -0,0 +1,244 @@
+use std::collections::HashSet;
+
+use crate::event::{Event, EventType};
+use crate::state::AgentState;
+use crate::types::{MessageId, ToolCallId};
+
+/// Errors reported by [`EventStreamValidator`] when an event sequence violates the AG-UI
+/// lifecycle ordering.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum StreamValidationError {
+    /// A `RunStarted` arrived while a run was still open, or another event arrived before any
+    /// `RunStarted` or after the run already closed (a repeated `RunFinished`/`RunError` is
+    /// reported as [`Self::DuplicateRunFinished`] instead, and a new `RunStarted` after the close
+    /// starts a fresh run).
+    #[error("unexpected {0:?} event: no run is currently active")]
+    UnexpectedEvent(EventType),
+
+    /// A `TextMessageContent`/`TextMessageEnd` referenced a `messageId` that was never opened by
+    /// a `TextMessageStart`, or was already closed.
+    #[error("{0:?} references unknown or already-closed message id {1}")]
+    UnknownMessageId(EventType, MessageId),
+
+    /// A `ToolCallArgs`/`ToolCallEnd` referenced a `toolCallId` that was never opened by a
+    /// `ToolCallStart`, or was already closed.
+    #[error("{0:?} references unknown or already-closed tool call id {1}")]
+    UnknownToolCallId(EventType, ToolCallId),
+
+    /// A `StepFinished` name didn't match the innermost open `StepStarted` name.
+    #[error("StepFinished({0:?}) does not match the innermost open step {1:?}")]
+    UnbalancedStep(String, String),
+
+    /// A `StepFinished` arrived with no steps open at all.
+    #[error("StepFinished({0:?}) arrived with no step currently open")]
+    StepNotStarted(String),
+
+    /// `ThinkingTextMessageContent`/`ThinkingTextMessageEnd` arrived with no
+    /// `ThinkingTextMessageStart` open.
+    #[error("{0:?} arrived with no thinking text message currently open")]
+    ThinkingTextNotStarted(EventType),
+
+    /// `ThinkingEnd` arrived with no `ThinkingStart` open.
+    #[error("ThinkingEnd arrived with no thinking step currently open")]
+    ThinkingNotStarted,
+
+    /// A second `RunFinished`/`RunError` arrived after the run already closed.
+    #[error("duplicate {0:?}: the run is already closed")]
+    DuplicateRunFinished(EventType),
+
+    /// `validate_all` reached the end of the sequence without a closing `RunFinished`/`RunError`.
+    /// Messages, tool calls, or steps left open in a closed run are reported by the variants
+    /// below.
+    #[error("stream ended with the run still open (no RunFinished/RunError)")]
+    UnterminatedRun,
+
+    /// `validate_all` reached a closing `RunFinished`/`RunError` with a message still open.
+    #[error("message {0} was never closed by TextMessageEnd")]
+    UnterminatedMessage(MessageId),
+
+    /// `validate_all` reached a closing `RunFinished`/`RunError` with a tool call still open.
+    #[error("tool call {0} was never closed by ToolCallEnd")]
+    UnterminatedToolCall(ToolCallId),
+
+    /// `validate_all` reached a closing `RunFinished`/`RunError` with a step still open.
+    #[error("step {0:?} was never closed by StepFinished")]
+    UnterminatedStep(String),
+}
+
+/// A stateful validator that enforces the AG-UI protocol's lifecycle ordering across a whole
+/// run: a single `RunStarted` precedes everything else, exactly one `RunFinished`/`RunError`
+/// closes it, every `TextMessageContent`/`TextMessageEnd` references a `messageId` opened by a
+/// prior `TextMessageStart` and not yet ended, every `ToolCallArgs`/`ToolCallEnd` references an
+/// open `toolCallId`, `StepStarted`/`StepFinished` names are balanced like a stack, and thinking
+/// start/content/end pairs are properly nested.
+///
+/// Feed events one at a time with [`push`](Self::push), or validate a complete, already-buffered
+/// sequence with [`validate_all`](Self::validate_all).
+#[derive(Debug, Default)]
+pub struct EventStreamValidator {
+    run_started: bool,
+    run_closed: bool,
+    open_messages: HashSet<MessageId>,
+    open_tool_calls: HashSet<ToolCallId>,
+    open_steps: Vec<String>,
+    thinking_step_open: bool,
+    thinking_text_open: bool,
+}
+
+impl EventStreamValidator {
+    /// Creates a validator expecting a fresh run, starting with `RunStarted`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates a single incremental event against the state accumulated so far.
+    pub fn push<StateT: AgentState>(
+        &mut self,
+        event: &Event<StateT>,
+    ) -> Result<(), StreamValidationError> {
+        let event_type = event.event_type();
+
+        if let Event::RunStarted(_) = event {
+            if self.run_started && !self.run_closed {
+                return Err(StreamValidationError::UnexpectedEvent(event_type));
+            }
+            *self = Self::new();
+            self.run_started = true;
+            return Ok(());
+        }
+
+        if self.run_closed && matches!(event, Event::RunFinished(_) | Event::RunError(_)) {
+            return Err(StreamValidationError::DuplicateRunFinished(event_type));
+        }
+        if !self.run_started || self.run_closed {
+            return Err(StreamValidationError::UnexpectedEvent(event_type));
+        }
+
+        match event {
+            Event::RunStarted(_) => unreachable!("handled above"),
+            Event::RunFinished(_) | Event::RunError(_) => {
+                self.run_closed = true;
+            }
+            Event::TextMessageStart(e) => {
+                self.open_messages.insert(e.message_id.clone());
+            }
+            Event::TextMessageContent(e) if !self.open_messages.contains(&e.message_id) => {
+                return Err(StreamValidationError::UnknownMessageId(
+                    event_type,
+                    e.message_id.clone(),
+                ));
+            }
+            Event::TextMessageContent(_) => {}
+            Event::TextMessageEnd(e) if !self.open_messages.remove(&e.message_id) => {
+                return Err(StreamValidationError::UnknownMessageId(
+                    event_type,
+                    e.message_id.clone(),
+                ));
+            }
+            Event::TextMessageEnd(_) => {}
+            Event::ToolCallStart(e) => {
+                self.open_tool_calls.insert(e.tool_call_id.clone());
+            }
+            Event::ToolCallArgs(e) if !self.open_tool_calls.contains(&e.tool_call_id) => {
+                return Err(StreamValidationError::UnknownToolCallId(
+                    event_type,
+                    e.tool_call_id.clone(),
+                ));
+            }
+            Event::ToolCallArgs(_) => {}
+            Event::ToolCallEnd(e) if !self.open_tool_calls.remove(&e.tool_call_id) => {
+                return Err(StreamValidationError::UnknownToolCallId(
+                    event_type,
+                    e.tool_call_id.clone(),
+                ));
+            }
+            Event::ToolCallEnd(_) => {}
+            Event::ThinkingStart(_) => {
+                self.thinking_step_open = true;
+            }
+            Event::ThinkingEnd(_) if !self.thinking_step_open => {
+                return Err(StreamValidationError::ThinkingNotStarted);
+            }
+            Event::ThinkingEnd(_) => {
+                self.thinking_step_open = false;
+            }
+            Event::ThinkingTextMessageStart(_) => {
+                self.thinking_text_open = true;
+            }
+            Event::ThinkingTextMessageContent(_) => {
+                if !self.thinking_text_open {
+                    return Err(StreamValidationError::ThinkingTextNotStarted(event_type));
+                }
+            }
+            Event::ThinkingTextMessageEnd(_) => {
+                if !self.thinking_text_open {
+                    return Err(StreamValidationError::ThinkingTextNotStarted(event_type));
+                }
+                self.thinking_text_open = false;
+            }
+            Event::StepStarted(e) => {
+                self.open_steps.push(e.step_name.clone());
+            }
+            Event::StepFinished(e) => match self.open_steps.last() {
+                Some(name) if *name == e.step_name => {
+                    self.open_steps.pop();
+                }
+                Some(name) => {
+                    return Err(StreamValidationError::UnbalancedStep(
+                        e.step_name.clone(),
+                        name.clone(),
+                    ));
+                }
+                None => {
+                    return Err(StreamValidationError::StepNotStarted(e.step_name.clone()));
+                }
+            },
+            // Chunk events, snapshots/deltas, and raw/custom events don't participate in the
+            // lifecycle ordering this validator enforces.
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// True once a `RunFinished`/`RunError` has closed the run this validator tracked.
+    pub fn is_closed(&self) -> bool {
+        self.run_closed
+    }
+
+    /// Validates that the run, as seen so far, ended cleanly: closed by exactly one
+    /// `RunFinished`/`RunError`, with no messages, tool calls, or steps left open.
+    pub fn finish(&self) -> Result<(), StreamValidationError> {
+        if !self.run_closed {
+            return Err(StreamValidationError::UnterminatedRun);
+        }
+        if let Some(message_id) = self.open_messages.iter().next() {
+            return Err(StreamValidationError::UnterminatedMessage(
+                message_id.clone(),
+            ));
+        }
+        if let Some(tool_call_id) = self.open_tool_calls.iter().next() {
+            return Err(StreamValidationError::UnterminatedToolCall(
+                tool_call_id.clone(),
+            ));
+        }
+        if let Some(step_name) = self.open_steps.last() {
+            return Err(StreamValidationError::UnterminatedStep(step_name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Validates a complete, already-buffered sequence of events from scratch, including that it
+    /// ends cleanly. Equivalent to `push`-ing every event in order and then calling
+    /// [`finish`](Self::finish).
+    pub fn validate_all<StateT: AgentState>(
+        events: &[Event<StateT>],
+    ) -> Result<(), StreamValidationError> {
+        let mut validator = Self::new();
+        for event in events {
+            validator.push(event)?;
+        }
+        validator.finish()
+    }
+}
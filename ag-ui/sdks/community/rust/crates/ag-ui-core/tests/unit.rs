@@ -2,9 +2,9 @@
 mod tests {
     use ag_ui_core::error::AgUiError;
     use ag_ui_core::types::{
-        AssistantMessage, Context, DeveloperMessage, FunctionCall, Message, MessageId, Role,
-        RunAgentInput, RunId, SystemMessage, ThreadId, Tool, ToolCall, ToolCallId, ToolMessage,
-        UserMessage,
+        AssistantMessage, Context, DeveloperMessage, FunctionCall, Message, MessageContent,
+        MessageId, Role, RunAgentInput, RunId, SystemMessage, ThreadId, Tool, ToolCall,
+        ToolCallId, ToolMessage, UserMessage,
     };
     use serde::{Deserialize, Serialize};
     use serde_json::json;
@@ -46,7 +46,7 @@ mod tests {
     fn test_message_serialization() {
         let user_msg = Message::User {
             id: MessageId::random(),
-            content: "Hello".to_string(),
+            content: "Hello".into(),
             name: None,
         };
 
@@ -73,7 +73,7 @@ mod tests {
             .with_content("Hello".to_string())
             .with_name("Assistant".to_string());
 
-        assert_eq!(msg.content, Some("Hello".to_string()));
+        assert_eq!(msg.content, Some(MessageContent::text("Hello")));
         assert_eq!(msg.name, Some("Assistant".to_string()));
     }
 
@@ -176,7 +176,9 @@ mod tests {
                 assert_eq!(id.to_string(), "00000000-0000-0000-0000-000000000000");
                 assert_eq!(
                     content,
-                    Some("I'll help you with that function.".to_string())
+                    Some(MessageContent::text(
+                        "I'll help you with that function."
+                    ))
                 );
                 assert_eq!(name, Some("CodeHelper".to_string()));
                 assert!(tool_calls.is_some());
@@ -217,7 +219,7 @@ mod tests {
         match &messages[0] {
             Message::User { id, content, name } => {
                 assert_eq!(id.to_string(), "00000000-0000-0000-0000-000000000000");
-                assert_eq!(content, "Hello!");
+                assert_eq!(content.as_text(), "Hello!");
                 assert_eq!(*name, Some("Alice".to_string()));
             }
             _ => panic!("Wrong message type"),
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use ag_ui_core::event::{
+        BaseEvent, Event, RunFinishedEvent, RunStartedEvent, StepFinishedEvent, StepStartedEvent,
+        TextMessageContentEvent, TextMessageEndEvent, TextMessageStartEvent, ToolCallArgsEvent,
+        ToolCallEndEvent, ToolCallStartEvent,
+    };
+    use ag_ui_core::types::{MessageId, Role, RunId, ThreadId, ToolCallId};
+    use ag_ui_core::{EventStreamValidator, StreamValidationError};
+
+    fn base_event() -> BaseEvent {
+        BaseEvent {
+            timestamp: None,
+            raw_event: None,
+        }
+    }
+
+    fn run_started() -> Event {
+        Event::RunStarted(RunStartedEvent {
+            base: base_event(),
+            thread_id: ThreadId::random(),
+            run_id: RunId::random(),
+        })
+    }
+
+    fn run_finished() -> Event {
+        Event::RunFinished(RunFinishedEvent {
+            base: base_event(),
+            thread_id: ThreadId::random(),
+            run_id: RunId::random(),
+            result: None,
+        })
+    }
+
+    #[test]
+    fn test_validate_all_accepts_a_well_formed_run() {
+        let message_id = MessageId::random();
+        let events = vec![
+            run_started(),
+            Event::StepStarted(StepStartedEvent {
+                base: base_event(),
+                step_name: "plan".to_string(),
+            }),
+            Event::TextMessageStart(TextMessageStartEvent {
+                base: base_event(),
+                message_id: message_id.clone(),
+                role: Role::Assistant,
+            }),
+            Event::TextMessageContent(TextMessageContentEvent {
+                base: base_event(),
+                message_id: message_id.clone(),
+                delta: "hi".to_string(),
+            }),
+            Event::TextMessageEnd(TextMessageEndEvent {
+                base: base_event(),
+                message_id,
+            }),
+            Event::StepFinished(StepFinishedEvent {
+                base: base_event(),
+                step_name: "plan".to_string(),
+            }),
+            run_finished(),
+        ];
+
+        EventStreamValidator::validate_all(&events).unwrap();
+    }
+
+    #[test]
+    fn test_content_before_run_started_is_rejected() {
+        let events = vec![Event::TextMessageContent(TextMessageContentEvent {
+            base: base_event(),
+            message_id: MessageId::random(),
+            delta: "hi".to_string(),
+        })];
+
+        let err = EventStreamValidator::validate_all(&events).unwrap_err();
+        assert!(matches!(err, StreamValidationError::UnexpectedEvent(_)));
+    }
+
+    #[test]
+    fn test_content_for_unopened_message_is_rejected() {
+        let mut validator = EventStreamValidator::new();
+        validator.push(&run_started()).unwrap();
+
+        let err = validator
+            .push(&Event::TextMessageContent(TextMessageContentEvent {
+                base: base_event(),
+                message_id: MessageId::random(),
+                delta: "hi".to_string(),
+            }))
+            .unwrap_err();
+        assert!(matches!(err, StreamValidationError::UnknownMessageId(_, _)));
+    }
+
+    #[test]
+    fn test_unterminated_message_fails_on_finish() {
+        let mut validator = EventStreamValidator::new();
+        validator.push(&run_started()).unwrap();
+        validator
+            .push(&Event::TextMessageStart(TextMessageStartEvent {
+                base: base_event(),
+                message_id: MessageId::random(),
+                role: Role::Assistant,
+            }))
+            .unwrap();
+        validator.push(&run_finished()).unwrap();
+
+        let err = validator.finish().unwrap_err();
+        assert!(matches!(
+            err,
+            StreamValidationError::UnterminatedMessage(_)
+        ));
+    }
+
+    #[test]
+    fn test_unbalanced_step_names_are_rejected() {
+        let mut validator = EventStreamValidator::new();
+        validator.push(&run_started()).unwrap();
+        validator
+            .push(&Event::StepStarted(StepStartedEvent {
+                base: base_event(),
+                step_name: "outer".to_string(),
+            }))
+            .unwrap();
+
+        let err = validator
+            .push(&Event::StepFinished(StepFinishedEvent {
+                base: base_event(),
+                step_name: "inner".to_string(),
+            }))
+            .unwrap_err();
+        assert!(matches!(err, StreamValidationError::UnbalancedStep(_, _)));
+    }
+
+    #[test]
+    fn test_duplicate_run_finished_is_rejected() {
+        let mut validator = EventStreamValidator::new();
+        validator.push(&run_started()).unwrap();
+        validator.push(&run_finished()).unwrap();
+
+        let err = validator.push(&run_finished()).unwrap_err();
+        assert!(matches!(
+            err,
+            StreamValidationError::DuplicateRunFinished(_)
+        ));
+    }
+
+    #[test]
+    fn test_tool_call_args_for_unopened_tool_call_is_rejected() {
+        let mut validator = EventStreamValidator::new();
+        validator.push(&run_started()).unwrap();
+
+        let err = validator
+            .push(&Event::ToolCallArgs(ToolCallArgsEvent {
+                base: base_event(),
+                tool_call_id: ToolCallId::random(),
+                delta: "{}".to_string(),
+            }))
+            .unwrap_err();
+        assert!(matches!(err, StreamValidationError::UnknownToolCallId(_, _)));
+    }
+
+    #[test]
+    fn test_tool_call_lifecycle_is_accepted() {
+        let mut validator = EventStreamValidator::new();
+        let tool_call_id = ToolCallId::random();
+        validator.push(&run_started()).unwrap();
+        validator
+            .push(&Event::ToolCallStart(ToolCallStartEvent {
+                base: base_event(),
+                tool_call_id: tool_call_id.clone(),
+                tool_call_name: "search".to_string(),
+                parent_message_id: None,
+            }))
+            .unwrap();
+        validator
+            .push(&Event::ToolCallArgs(ToolCallArgsEvent {
+                base: base_event(),
+                tool_call_id: tool_call_id.clone(),
+                delta: "{}".to_string(),
+            }))
+            .unwrap();
+        validator
+            .push(&Event::ToolCallEnd(ToolCallEndEvent {
+                base: base_event(),
+                tool_call_id,
+            }))
+            .unwrap();
+        validator.push(&run_finished()).unwrap();
+        validator.finish().unwrap();
+    }
+}